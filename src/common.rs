@@ -1,9 +1,38 @@
 use std::{
     fmt::Display,
-    sync::mpsc::{channel, Receiver, Sender, TryRecvError},
+    os::windows::ffi::OsStrExt,
+    path::PathBuf,
+    sync::{
+        mpsc::{channel, Receiver, RecvError, Sender, TryRecvError},
+        Arc,
+    },
+    time::Instant,
 };
 
-use crate::prelude::MainEvents;
+use winapi::{
+    shared::{
+        minwindef::FALSE,
+        windef::{HCURSOR, HICON, HMONITOR, HWND, POINT},
+        winerror::{S_OK, WAIT_TIMEOUT},
+    },
+    um::{
+        handleapi::CloseHandle,
+        shellscalingapi::{GetDpiForMonitor, MDT_EFFECTIVE_DPI},
+        synchapi::{CreateEventW, SetEvent},
+        winnt::HANDLE,
+        winuser::{
+            LoadCursorW, MonitorFromPoint, MonitorFromWindow, MsgWaitForMultipleObjectsEx,
+            IDC_APPSTARTING, IDC_ARROW, IDC_CROSS, IDC_HAND, IDC_HELP, IDC_IBEAM, IDC_NO,
+            IDC_SIZEALL, IDC_SIZENESW, IDC_SIZENS, IDC_SIZENWSE, IDC_SIZEWE, IDC_WAIT,
+            MONITOR_DEFAULTTONEAREST, MWMO_INPUTAVAILABLE, QS_ALLINPUT,
+        },
+    },
+};
+
+use crate::prelude::{MainEvents, WindowError};
+
+// winbase is not enabled only for this
+const INFINITE: u32 = 0xFFFFFFFF;
 
 /// # Wide String
 ///
@@ -40,7 +69,7 @@ impl Wstring {
     /// assert_eq!(wstring.len(), 9);
     /// ```
     pub fn len(&self) -> usize {
-        return self.data.len() - 1usize;
+        return self.data.len().saturating_sub(1usize);
     }
 
     /// Creates Wstring from &str type
@@ -121,7 +150,7 @@ impl Wstring {
     /// assert_eq!(wstring.len(), 20);
     /// ```
     pub fn push(&mut self, data: &str) {
-        let mut wstring: String = String::from_utf16_lossy(&self.data);
+        let mut wstring: String = String::from_utf16_lossy(&self.data[..self.len()]);
         wstring.push_str(data);
         self.data = wstring.encode_utf16().collect();
         self.data.push(0);
@@ -271,32 +300,34 @@ impl Astring {
 
 /// Creates and returns a pointer to Wstring
 ///
+/// The Wstring is dropped before the pointer is returned, so the pointer dangles. Keep a Wstring alive and use its as_ptr() instead
+///
 /// # Example
 ///
 /// ```
 /// let wstring = Wstring::from("WSTRING");
 /// func(wstring.as_ptr());
 ///
-/// func(wchar("WSTRING"));
-///
 /// // where func uses a constant pointer to Wstring
 /// ```
+#[deprecated(note = "the returned pointer dangles, keep a Wstring alive and use Wstring::as_ptr() instead")]
 pub fn wchar(data: &str) -> *const u16 {
     return Wstring::from(data).as_ptr();
 }
 
 /// Creates and returns a pointer to Astring
 ///
+/// The Astring is dropped before the pointer is returned, so the pointer dangles. Keep an Astring alive and use its as_ptr() instead
+///
 /// # Example
 ///
 /// ```
 /// let astring = Astring::from("ASTRING");
 /// func(astring.as_ptr());
 ///
-/// func(wchar("ASTRING"));
-///
 /// // where func uses a constant pointer to Astring
 /// ```
+#[deprecated(note = "the returned pointer dangles, keep an Astring alive and use Astring::as_ptr() instead")]
 pub fn achar(data: &str) -> *const i8 {
     return Astring::from(data).as_ptr();
 }
@@ -311,6 +342,7 @@ pub fn achar(data: &str) -> *const i8 {
 /// assert_eq!(size.height, 768);
 /// ```
 #[derive(Default, Debug, Clone, Copy, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct Size {
     /// Width i32 type
     pub width: i32,
@@ -329,6 +361,132 @@ impl Size {
     pub fn new(width: i32, height: i32) -> Self {
         return Self { width, height };
     }
+
+    /// Restricts the width and the height to the given range. Panics if min is greater than max
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// let size = Size::new(0, 5000).clamp(Size::new(1, 1), Size::new(1920, 1080));
+    /// assert_eq!(size, Size::new(1, 1080));
+    /// ```
+    pub fn clamp(&self, min: Size, max: Size) -> Self {
+        return Self {
+            width: self.width.clamp(min.width, max.width),
+            height: self.height.clamp(min.height, max.height),
+        };
+    }
+
+    /// Returns the larger width and the larger height of both sizes
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// assert_eq!(Size::new(800, 300).max(Size::new(640, 480)), Size::new(800, 480));
+    /// ```
+    pub fn max(self, other: Size) -> Self {
+        return Self {
+            width: self.width.max(other.width),
+            height: self.height.max(other.height),
+        };
+    }
+
+    /// Returns the smaller width and the smaller height of both sizes
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// assert_eq!(Size::new(800, 300).min(Size::new(640, 480)), Size::new(640, 300));
+    /// ```
+    pub fn min(self, other: Size) -> Self {
+        return Self {
+            width: self.width.min(other.width),
+            height: self.height.min(other.height),
+        };
+    }
+
+    /// Multiplies the width and the height by the factor and rounds them to the nearest integer (halves are rounded away from zero)
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// assert_eq!(Size::new(101, 50).scale(1.5), Size::new(152, 75));
+    /// ```
+    pub fn scale(&self, factor: f64) -> Self {
+        return Self {
+            width: scale(self.width, factor),
+            height: scale(self.height, factor),
+        };
+    }
+
+    /// Converts a logical size (in the 96 DPI pixels) into the physical pixels of a monitor with the given scale factor
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// assert_eq!(Size::to_physical(Size::new(800, 600), 1.25), Size::new(1000, 750));
+    /// ```
+    pub fn to_physical(logical: Size, scale_factor: f64) -> Self {
+        return logical.scale(scale_factor);
+    }
+
+    /// Converts a size in the physical pixels of a monitor with the given scale factor into a logical size (in the 96 DPI pixels)
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// assert_eq!(Size::to_logical(Size::new(1000, 750), 1.25), Size::new(800, 600));
+    /// ```
+    pub fn to_logical(physical: Size, scale_factor: f64) -> Self {
+        return physical.scale(1.0f64 / scale_factor);
+    }
+
+    /// Interpolates linearly between two sizes where t = 0.0 gives a and t = 1.0 gives b. Combine it with the functions of the ease module for a smooth animation
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// assert_eq!(Size::lerp(Size::new(100, 100), Size::new(200, 300), 0.5), Size::new(150, 200));
+    /// ```
+    pub fn lerp(a: Size, b: Size, t: f32) -> Self {
+        return Self {
+            width: lerp(a.width, b.width, t),
+            height: lerp(a.height, b.height, t),
+        };
+    }
+}
+
+// The effective DPI of the monitor divided by 96. A process which is not DPI aware (see ManagerBuilder::with_dpi_aware()) is told 96 for every monitor and its windows are stretched by the system instead
+unsafe fn monitor_scale_factor(monitor: HMONITOR) -> f64 {
+    let (mut dpi_x, mut dpi_y) = (0u32, 0u32);
+    if GetDpiForMonitor(monitor, MDT_EFFECTIVE_DPI, &mut dpi_x, &mut dpi_y) != S_OK {
+        return 1.0f64;
+    }
+
+    return dpi_x as f64 / 96.0f64;
+}
+
+// The scale factor of the monitor the window is on
+pub(crate) unsafe fn scale_factor(hwnd: HWND) -> f64 {
+    return monitor_scale_factor(MonitorFromWindow(hwnd, MONITOR_DEFAULTTONEAREST));
+}
+
+// The scale factor of the monitor which contains the point (or is the nearest to it)
+pub(crate) unsafe fn scale_factor_at(point: Point) -> f64 {
+    let point = POINT {
+        x: point.x,
+        y: point.y,
+    };
+    return monitor_scale_factor(MonitorFromPoint(point, MONITOR_DEFAULTTONEAREST));
+}
+
+pub(crate) fn lerp(a: i32, b: i32, t: f32) -> i32 {
+    return (a as f64 + (b - a) as f64 * t as f64).round() as i32;
+}
+
+// Every DPI conversion goes through here so that they are all rounded the same way
+pub(crate) fn scale(value: i32, factor: f64) -> i32 {
+    return (value as f64 * factor).round() as i32;
 }
 
 /// A 2D point
@@ -341,6 +499,7 @@ impl Size {
 /// assert_eq!(pos.y, 78);
 /// ```
 #[derive(Default, Debug, Clone, Copy, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct Point {
     /// X pos i32 type
     pub x: i32,
@@ -359,6 +518,65 @@ impl Point {
     pub fn new(x: i32, y: i32) -> Self {
         return Self { x, y };
     }
+
+    /// Restricts the x and the y positions to the given range. Panics if min is greater than max
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// let point = Point::new(-20, 300).clamp(Point::new(0, 0), Point::new(100, 100));
+    /// assert_eq!(point, Point::new(0, 100));
+    /// ```
+    pub fn clamp(&self, min: Point, max: Point) -> Self {
+        return Self {
+            x: self.x.clamp(min.x, max.x),
+            y: self.y.clamp(min.y, max.y),
+        };
+    }
+
+    /// Returns the larger x and the larger y of both points
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// assert_eq!(Point::new(-20, 300).max(Point::new(0, 100)), Point::new(0, 300));
+    /// ```
+    pub fn max(self, other: Point) -> Self {
+        return Self {
+            x: self.x.max(other.x),
+            y: self.y.max(other.y),
+        };
+    }
+
+    /// Returns the smaller x and the smaller y of both points
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// assert_eq!(Point::new(-20, 300).min(Point::new(0, 100)), Point::new(-20, 100));
+    /// ```
+    pub fn min(self, other: Point) -> Self {
+        return Self {
+            x: self.x.min(other.x),
+            y: self.y.min(other.y),
+        };
+    }
+
+    /// Interpolates linearly between two points where t = 0.0 gives a and t = 1.0 gives b. Combine it with the functions of the ease module for a smooth animation
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// let start = Point::new(0, 0);
+    /// let end = Point::new(400, 100);
+    /// assert_eq!(Point::lerp(start, end, ease::ease_in_out(0.5)), Point::new(200, 50));
+    /// ```
+    pub fn lerp(a: Point, b: Point, t: f32) -> Self {
+        return Self {
+            x: lerp(a.x, b.x, t),
+            y: lerp(a.y, b.y, t),
+        };
+    }
 }
 
 impl Display for Point {
@@ -367,17 +585,195 @@ impl Display for Point {
     }
 }
 
-pub(crate) fn load_icon(path: &str) -> *mut winapi::ctypes::c_void {
-    return unsafe {
+/// A rectangle given by its left-top and right-bottom corners
+///
+/// # Example
+///
+/// ```
+/// let rect = Rect::new(10, 20, 110, 70);
+/// assert_eq!(rect.width(), 100);
+/// assert_eq!(rect.height(), 50);
+/// ```
+#[derive(Default, Debug, Clone, Copy, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct Rect {
+    /// Left side i32 type
+    pub left: i32,
+    /// Top side i32 type
+    pub top: i32,
+    /// Right side i32 type (exclusive)
+    pub right: i32,
+    /// Bottom side i32 type (exclusive)
+    pub bottom: i32,
+}
+
+impl Rect {
+    /// Creates a new Rect
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// let rect = Rect::new(0, 0, 800, 640);
+    /// ```
+    pub fn new(left: i32, top: i32, right: i32, bottom: i32) -> Self {
+        return Self {
+            left,
+            top,
+            right,
+            bottom,
+        };
+    }
+
+    /// Creates a new Rect from its left-top corner and its size
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// let rect = Rect::from_pos_size(Point::new(10, 10), Size::new(100, 50));
+    /// assert_eq!(rect, Rect::new(10, 10, 110, 60));
+    /// ```
+    pub fn from_pos_size(pos: Point, size: Size) -> Self {
+        return Self::new(pos.x, pos.y, pos.x + size.width, pos.y + size.height);
+    }
+
+    /// Returns the width of the Rect
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// assert_eq!(Rect::new(10, 0, 30, 0).width(), 20);
+    /// ```
+    pub fn width(&self) -> i32 {
+        return self.right - self.left;
+    }
+
+    /// Returns the height of the Rect
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// assert_eq!(Rect::new(0, 10, 0, 30).height(), 20);
+    /// ```
+    pub fn height(&self) -> i32 {
+        return self.bottom - self.top;
+    }
+
+    /// Returns the left-top corner of the Rect
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// assert_eq!(Rect::new(10, 20, 30, 40).pos(), Point::new(10, 20));
+    /// ```
+    pub fn pos(&self) -> Point {
+        return Point::new(self.left, self.top);
+    }
+
+    /// Returns the size of the Rect
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// assert_eq!(Rect::new(10, 20, 30, 60).size(), Size::new(20, 40));
+    /// ```
+    pub fn size(&self) -> Size {
+        return Size::new(self.width(), self.height());
+    }
+
+    /// Returns true if the point is inside the Rect
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// let rect = Rect::new(0, 0, 100, 100);
+    /// assert!(rect.contains(Point::new(50, 50)));
+    /// assert!(!rect.contains(Point::new(100, 50)));
+    /// ```
+    pub fn contains(&self, point: Point) -> bool {
+        return point.x >= self.left && point.x < self.right && point.y >= self.top && point.y < self.bottom;
+    }
+
+    /// Multiplies every side by the factor and rounds them to the nearest integer. The sides are rounded one by one, so the Rects which touch each other still touch after scaling
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// assert_eq!(Rect::new(10, 10, 110, 60).scale(2.0), Rect::new(20, 20, 220, 120));
+    /// ```
+    pub fn scale(&self, factor: f64) -> Self {
+        return Self {
+            left: scale(self.left, factor),
+            top: scale(self.top, factor),
+            right: scale(self.right, factor),
+            bottom: scale(self.bottom, factor),
+        };
+    }
+}
+
+impl Display for Rect {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(
+            f,
+            "(left: {}, top: {}, right: {}, bottom: {})",
+            self.left, self.top, self.right, self.bottom
+        )
+    }
+}
+
+pub(crate) fn resolve_path(path: &str) -> PathBuf {
+    let path = PathBuf::from(path);
+    if path.is_absolute() || path.exists() {
+        return path;
+    }
+
+    return std::env::current_exe()
+        .ok()
+        .and_then(|exe| exe.parent().map(|dir| dir.join(&path)))
+        .filter(|path| path.exists())
+        .unwrap_or(path);
+}
+
+pub(crate) fn load_icon(path: &str) -> Result<HICON, WindowError> {
+    // Win32 would silently load the file named by the part before the NUL
+    if path.contains('\0') {
+        return Err(WindowError::IconPathNul(path.to_owned()));
+    }
+
+    let path = resolve_path(path);
+    if !path.is_file() {
+        return Err(WindowError::IconNotFound(path));
+    }
+
+    let mut wpath: Vec<u16> = path.as_os_str().encode_wide().collect();
+    wpath.push(0);
+
+    let icon = unsafe {
         winapi::um::winuser::LoadImageW(
             std::ptr::null_mut(),
-            wchar(path),
+            wpath.as_ptr(),
             winapi::um::winuser::IMAGE_ICON,
             0,
             0,
             winapi::um::winuser::LR_LOADFROMFILE,
         )
     };
+
+    if icon.is_null() {
+        let code = unsafe { winapi::um::errhandlingapi::GetLastError() };
+        return Err(WindowError::IconLoad { path, code });
+    }
+
+    return Ok(icon as HICON);
+}
+
+// The icons of a class are loaded by load_icon() and the big and the small one may be the same handle
+pub(crate) unsafe fn destroy_icons(icon: HICON, small_icon: HICON) {
+    if !icon.is_null() {
+        winapi::um::winuser::DestroyIcon(icon);
+    }
+    if !small_icon.is_null() && small_icon != icon {
+        winapi::um::winuser::DestroyIcon(small_icon);
+    }
 }
 
 /// Theme of the GUI (only Light or Dark).
@@ -416,10 +812,95 @@ impl std::fmt::Display for Theme {
     }
 }
 
+/// Standard cursors of the system
+///
+/// # Example
+///
+/// ```ignore
+/// manager.set_default_cursor(Cursor::Crosshair);
+/// ```
+#[derive(Default, Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum Cursor {
+    /// The normal arrow
+    #[default]
+    Arrow,
+    /// The text cursor
+    IBeam,
+    /// The busy cursor
+    Wait,
+    /// The arrow with a small busy cursor
+    AppStarting,
+    /// The crosshair
+    Crosshair,
+    /// The hand which is used for links
+    Hand,
+    /// The arrow with a question mark
+    Help,
+    /// The slashed circle
+    NotAllowed,
+    /// The four-pointed arrow for moving
+    Move,
+    /// The arrow pointing north and south
+    ResizeNS,
+    /// The arrow pointing west and east
+    ResizeWE,
+    /// The arrow pointing northwest and southeast
+    ResizeNWSE,
+    /// The arrow pointing northeast and southwest
+    ResizeNESW,
+}
+
+impl Cursor {
+    pub(crate) fn load(&self) -> HCURSOR {
+        let name = match self {
+            Cursor::Arrow => IDC_ARROW,
+            Cursor::IBeam => IDC_IBEAM,
+            Cursor::Wait => IDC_WAIT,
+            Cursor::AppStarting => IDC_APPSTARTING,
+            Cursor::Crosshair => IDC_CROSS,
+            Cursor::Hand => IDC_HAND,
+            Cursor::Help => IDC_HELP,
+            Cursor::NotAllowed => IDC_NO,
+            Cursor::Move => IDC_SIZEALL,
+            Cursor::ResizeNS => IDC_SIZENS,
+            Cursor::ResizeWE => IDC_SIZEWE,
+            Cursor::ResizeNWSE => IDC_SIZENWSE,
+            Cursor::ResizeNESW => IDC_SIZENESW,
+        };
+
+        // The system cursors are shared, so they are never destroyed
+        return unsafe { LoadCursorW(std::ptr::null_mut(), name) };
+    }
+}
+
+// Wakes up the main thread which waits in Messenger::wait_until() when an event is sent
+#[derive(Debug)]
+struct Wakeup {
+    event: usize,
+}
+
+impl Wakeup {
+    fn new() -> Self {
+        let event = unsafe { CreateEventW(std::ptr::null_mut(), FALSE, FALSE, std::ptr::null()) };
+        return Self {
+            event: event as usize,
+        };
+    }
+}
+
+impl Drop for Wakeup {
+    fn drop(&mut self) {
+        unsafe {
+            CloseHandle(self.event as HANDLE);
+        }
+    }
+}
+
 #[derive(Debug)]
 pub(crate) struct Messenger {
     receiver: Option<Receiver<MainEvents>>,
     sender: Sender<MainEvents>,
+    wakeup: Arc<Wakeup>,
 }
 
 impl Default for Messenger {
@@ -429,6 +910,7 @@ impl Default for Messenger {
         Self {
             receiver: Some(receiver),
             sender: sender,
+            wakeup: Arc::new(Wakeup::new()),
         }
     }
 }
@@ -440,6 +922,7 @@ impl Messenger {
         Self {
             receiver: Some(receiver),
             sender,
+            wakeup: Arc::new(Wakeup::new()),
         }
     }
 
@@ -447,6 +930,7 @@ impl Messenger {
         Self {
             receiver: None,
             sender: self.sender.clone(),
+            wakeup: self.wakeup.clone(),
         }
     }
 
@@ -454,7 +938,46 @@ impl Messenger {
         self.receiver.as_ref().unwrap().try_recv()
     }
 
+    pub(crate) fn recv(&self) -> Result<MainEvents, RecvError> {
+        self.receiver.as_ref().unwrap().recv()
+    }
+
     pub(crate) fn send(&self, events: MainEvents) {
         self.sender.send(events).unwrap();
+        unsafe {
+            SetEvent(self.wakeup.event as HANDLE);
+        }
+    }
+
+    // Like send() but the event is dropped if the Manager is already gone
+    pub(crate) fn try_send(&self, events: MainEvents) {
+        if self.sender.send(events).is_ok() {
+            unsafe {
+                SetEvent(self.wakeup.event as HANDLE);
+            }
+        }
+    }
+
+    // Returns false if the deadline has been reached without any event or message
+    pub(crate) fn wait_until(&self, deadline: Instant) -> bool {
+        let remaining = deadline.saturating_duration_since(Instant::now());
+        // Rounded up so that it does not wake up just before the deadline
+        let timeout = remaining.as_micros().div_ceil(1000).min(INFINITE as u128 - 1) as u32;
+        return self.wait_for(timeout);
+    }
+
+    // Blocks until an event or a message arrives
+    pub(crate) fn wait(&self) {
+        self.wait_for(INFINITE);
+    }
+
+    fn wait_for(&self, timeout: u32) -> bool {
+        let handle = self.wakeup.event as HANDLE;
+
+        let result = unsafe {
+            MsgWaitForMultipleObjectsEx(1, &handle, timeout, QS_ALLINPUT, MWMO_INPUTAVAILABLE)
+        };
+
+        return result != WAIT_TIMEOUT;
     }
 }
@@ -1,3 +1,5 @@
+use std::time::Instant;
+
 /// Control flow of the Manager.
 /// # Example
 /// 
@@ -16,11 +18,25 @@
 /// ```
 #[derive(Default, Copy, Clone, PartialEq, Debug)]
 pub enum ControlFlow {
-    /// Do not do anything
+    /// Do not do anything. The loop keeps polling for the events without sleeping and the closure is called only when an event arrives
     #[default]
     Continue,
+    /// Poll for the events without sleeping like Continue, but the closure is also called with Events::None on every pass of the loop, so a game can update and render every frame. It stays Poll until it is changed. Manager::set_frame_rate_limit() keeps it from using a whole CPU core
+    Poll,
     /// Exit the program
     Exit,
-    /// Exit the program and panic
+    /// Exit the program like Exit and keep the code, which Manager::exit_code() returns afterwards (e.g. to give it to std::process::exit())
     ExitWithCode(u32),
+    /// Sleep until an event arrives, so an idle program does not use the CPU. It stays Wait until it is changed. With Manager::set_wait_timeout() the closure is also called with Events::AboutToWait when no event has arrived for that long
+    Wait,
+    /// Sleep until either an event arrives or the given time is reached. When the time is reached without any event the closure is called with Events::None and the control flow is Continue again, so set WaitUntil again for the next tick. A time which has already passed does not sleep at all: the pending events are handled and then Events::None is sent right away
+    ///
+    /// ```ignore
+    /// // Redraws the animation about 60 times per second without a busy loop
+    /// Events::None => {
+    ///     animation.step();
+    ///     *control_flow = ControlFlow::WaitUntil(Instant::now() + Duration::from_millis(16));
+    /// }
+    /// ```
+    WaitUntil(Instant),
 }
\ No newline at end of file
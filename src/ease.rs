@@ -0,0 +1,48 @@
+//! Easing functions for animations. Each of them maps the progress t (0.0 at the start, 1.0 at the end) to the eased progress which can be given to Point::lerp() or Size::lerp()
+//!
+//! # Example
+//!
+//! ```ignore
+//! let t = (timer.time() - start) / duration;
+//! let pos = Point::lerp(from, to, ease::ease_out_cubic(t));
+//! window.set_pos(pos.x, pos.y);
+//! ```
+
+/// Keeps the progress as it is, so the animation moves at a constant speed. t is clamped to 0.0..=1.0
+///
+/// # Example
+///
+/// ```
+/// assert_eq!(ease::linear(0.25), 0.25);
+/// ```
+pub fn linear(t: f32) -> f32 {
+    return t.clamp(0.0f32, 1.0f32);
+}
+
+/// Starts slowly, speeds up in the middle and slows down at the end. t is clamped to 0.0..=1.0
+///
+/// # Example
+///
+/// ```
+/// assert_eq!(ease::ease_in_out(0.5), 0.5);
+/// ```
+pub fn ease_in_out(t: f32) -> f32 {
+    let t = linear(t);
+    return if t < 0.5f32 {
+        2.0f32 * t * t
+    } else {
+        1.0f32 - (-2.0f32 * t + 2.0f32).powi(2) / 2.0f32
+    };
+}
+
+/// Starts fast and slows down towards the end, which feels natural for the windows which slide into place. t is clamped to 0.0..=1.0
+///
+/// # Example
+///
+/// ```
+/// assert_eq!(ease::ease_out_cubic(1.0), 1.0);
+/// ```
+pub fn ease_out_cubic(t: f32) -> f32 {
+    let t = linear(t);
+    return 1.0f32 - (1.0f32 - t).powi(3);
+}
@@ -0,0 +1,71 @@
+use std::{path::PathBuf, time::Duration};
+
+/// Errors which can occur while creating or changing windows
+///
+/// # Example
+///
+/// ```ignore
+/// if let Err(error) = manager.window().unwrap().set_icon("icon.ico") {
+///     println!("[ERROR]: {error}");
+/// }
+/// ```
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum WindowError {
+    /// The icon file does not exist (neither in the current directory nor in the directory of the executable)
+    IconNotFound(PathBuf),
+    /// The icon file exists but could not be loaded (e.g. it is not a valid .ico file). Code is the result of GetLastError()
+    IconLoad { path: PathBuf, code: u32 },
+    /// The icon path contains a NUL character which would cut the path short when it is given to Win32
+    IconPathNul(String),
+    /// The width or the height of the WindowBuilder is not positive or does not fit into the window coordinates (i16::MAX)
+    InvalidSize { width: i32, height: i32 },
+    /// The position of the WindowBuilder does not fit into the window coordinates (i16::MIN..=i16::MAX)
+    InvalidPosition { x: i32, y: i32 },
+    /// The AppUserModelID could not be set (e.g. it is longer than 128 characters). Code is the returned HRESULT
+    AppUserModelId { id: String, code: i32 },
+    /// The window class could not be registered (e.g. another window already uses the class name). Code is the result of GetLastError()
+    ClassRegistration { class: String, code: u32 },
+    /// The window could not be created. Code is the result of GetLastError()
+    Creation { class: String, code: u32 },
+    /// The thread of the window has not reported back within the given time
+    Timeout { class: String, timeout: Duration },
+}
+
+impl std::fmt::Display for WindowError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::IconNotFound(path) => write!(f, "icon file {} is not found", path.display()),
+            Self::IconLoad { path, code } => {
+                write!(f, "icon file {} could not be loaded (error code: {code})", path.display())
+            }
+            Self::IconPathNul(path) => {
+                write!(f, "icon path {:?} contains a NUL character", path)
+            }
+            Self::InvalidSize { width, height } => write!(
+                f,
+                "window size {width}x{height} is invalid, both sides must be between 1 and {}",
+                i16::MAX
+            ),
+            Self::InvalidPosition { x, y } => write!(
+                f,
+                "window position ({x}, {y}) is invalid, both coordinates must be between {} and {}",
+                i16::MIN,
+                i16::MAX
+            ),
+            Self::AppUserModelId { id, code } => {
+                write!(f, "app user model id {id} could not be set (HRESULT: {code:#010x})")
+            }
+            Self::ClassRegistration { class, code } => {
+                write!(f, "window class {class} could not be registered (error code: {code})")
+            }
+            Self::Creation { class, code } => {
+                write!(f, "window of the class {class} could not be created (error code: {code})")
+            }
+            Self::Timeout { class, timeout } => {
+                write!(f, "window of the class {class} has not been created within {timeout:?}")
+            }
+        }
+    }
+}
+
+impl std::error::Error for WindowError {}
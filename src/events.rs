@@ -1,4 +1,12 @@
-use crate::common::Point;
+use std::path::PathBuf;
+
+use crate::{
+    common::{Point, Rect, Size},
+    input::Modifiers,
+    job::JobId,
+    keycodes::Button,
+    window::{MonitorId, WindowId},
+};
 
 /// The state of the buttons such as being pressed or released or none as well
 ///
@@ -9,7 +17,8 @@ use crate::common::Point;
 ///     println!("A key is released");
 /// }
 /// ```
-#[derive(Debug, Copy, Clone, PartialEq, Eq, PartialOrd, Ord)]
+#[derive(Default, Debug, Copy, Clone, PartialEq, Eq, PartialOrd, Ord)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub enum Action {
     /// When a key is pressed
     Press,
@@ -18,9 +27,21 @@ pub enum Action {
     /// When a key is down
     Down,
     /// When a key is idle
+    #[default]
     None,
 }
 
+impl std::fmt::Display for Action {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::Press => write!(f, "Press"),
+            Self::Release => write!(f, "Release"),
+            Self::Down => write!(f, "Down"),
+            Self::None => write!(f, "None"),
+        }
+    }
+}
+
 /// Main central Events that are sent by the window or windows via Manager sturct
 ///
 /// # Example
@@ -32,7 +53,7 @@ pub enum Action {
 ///     match events => {
 ///         Events::WindowEvent { id, event } match event {
 ///             WindowEvents::Close => *control_flow = ControlFlow::Exit,
-///             WindowEvents::Create => {
+///             WindowEvents::Create { .. } => {
 ///                 println!("A window is created with id: {id}");
 ///             }
 ///             _=> {}
@@ -41,19 +62,70 @@ pub enum Action {
 ///     }
 /// });
 /// ```
-#[derive(Copy, Clone, Default, Debug, PartialEq)]
+#[derive(Clone, Default, Debug, PartialEq)]
 pub enum Events {
     /// WindowEvents such as moving window or changing the size
-    WindowEvents { id: usize, event: WindowEvents },
+    WindowEvents { id: WindowId, event: WindowEvents },
     /// KeyboardEvents like pressing Left Shift button
-    KeyboardEvents { id: usize, event: KeyboardEvents },
+    KeyboardEvents { id: WindowId, event: KeyboardEvents },
     /// MouseEvents. For example, releasing Right Mouse Button or scrolling up and down
-    MouseEvents { id: usize, event: MouseEvents },
+    MouseEvents { id: WindowId, event: MouseEvents },
+    /// A window message which the crate does not translate. It is only sent after Manager::set_raw_message_passthrough(true)
+    RawMessage {
+        id: WindowId,
+        msg: u32,
+        wparam: usize,
+        lparam: isize,
+    },
+    /// Sent when a job which has been started with Manager::spawn() has finished (or panicked). Its result can then be taken from its JobHandle
+    JobFinished { id: JobId },
+    /// Sent when the thread of a window has panicked while handling a message. The message is the one of the panic. The window is then destroyed, so WindowEvents::Destroyed follows
+    Error { id: WindowId, message: String },
+    /// Sent in ControlFlow::Wait when no event has arrived within the timeout set with Manager::set_wait_timeout(), e.g. to blink a text cursor while the program is idle
+    AboutToWait,
     /// Idle form which means nothing is happening
     #[default]
     None,
 }
 
+impl Events {
+    /// Returns whether both events are the same kind of event (e.g. both are MouseEvents::MouseMove) while their window ids and payloads such as the positions or the keycodes are ignored
+    ///
+    /// # Example
+    ///
+    /// ```ignore
+    /// let mut last = Events::None;
+    ///
+    /// manager.run(|events, control_flow, manager| {
+    ///     if !events.same_kind(&last) {
+    ///         println!("{events:?}");
+    ///     }
+    ///     last = events;
+    /// });
+    /// ```
+    pub fn same_kind(&self, other: &Events) -> bool {
+        use std::mem::discriminant;
+
+        return match (self, other) {
+            (Events::WindowEvents { event: a, .. }, Events::WindowEvents { event: b, .. }) => {
+                discriminant(a) == discriminant(b)
+            }
+            (Events::KeyboardEvents { event: a, .. }, Events::KeyboardEvents { event: b, .. }) => {
+                discriminant(a) == discriminant(b)
+            }
+            (Events::MouseEvents { event: a, .. }, Events::MouseEvents { event: b, .. }) => {
+                discriminant(a) == discriminant(b)
+            }
+            (Events::RawMessage { .. }, Events::RawMessage { .. }) => true,
+            (Events::JobFinished { .. }, Events::JobFinished { .. }) => true,
+            (Events::Error { .. }, Events::Error { .. }) => true,
+            (Events::AboutToWait, Events::AboutToWait) => true,
+            (Events::None, Events::None) => true,
+            _ => false,
+        };
+    }
+}
+
 /// Specific window events
 ///
 /// # Example
@@ -77,26 +149,50 @@ pub enum Events {
 ///     }
 /// });
 /// ```
-#[derive(Copy, Clone, Debug, PartialEq)]
+#[derive(Clone, Debug, PartialEq)]
 pub enum WindowEvents {
-    /// Sent when a window is created
-    Create,
-    /// Sent when a window is closed
+    /// Sent when a window is created. size is the size of the client area, position is the position of the window and scale_factor is the DPI of its monitor divided by 96, so that e.g. a GPU surface can be created right away
+    Create {
+        size: Size,
+        position: Point,
+        scale_factor: f64,
+    },
+    /// Sent when the user asks to close a window (e.g. with the close button or Alt+F4). The window is then destroyed unless Window::set_prevent_close(true) has been called, in which case the program decides and calls Window::destroy() itself
+    CloseRequested,
+    /// Sent when a window is being destroyed. The window can still be queried from the Manager while this event is handled
     Close,
+    /// Sent after a window has been fully destroyed and removed from the Manager. It is the last event of the window
+    Destroyed,
     /// Sent when a window is maximized
     Maximized { width: i32, height: i32 },
     /// Sent when a window is minimized
     Minimized { width: i32, height: i32 },
-    /// Sent when a window size is changed
+    /// Sent when a window size is changed. The size is never 0x0 (Minimized is sent instead), however it is still better to guard against tiny sizes
     FramebufferChanged { width: i32, height: i32 },
+    /// Sent when the user starts to resize a window by dragging its border. FramebufferChanged keeps coming while the border is dragged
+    ResizeStarted,
+    /// Sent when the user has released the dragged border. The size is the final size of the client area, so it is the right moment for expensive work like recreating a swapchain
+    ResizeEnded { width: i32, height: i32 },
     /// Sent when a window is moved
     Moved { x: i32, y: i32 },
     /// Sent when a window has gained the focus
     SetFocus,
     /// Sent when a window has lost the focus
     LostFocus,
-    /// Sent when redrawing window
-    RedrawRequested,
+    /// Sent when redrawing window. The dirty rect is the part of the client area which has to be repainted (the whole client area after Window::request_redraw())
+    RedrawRequested { dirty: Rect },
+    /// Sent when a timer which has been set with Window::set_timer() elapses
+    Timer { id: usize },
+    /// Sent for every file which is being dragged over the window
+    HoveredFile { path: PathBuf },
+    /// Sent for every file which has been dropped onto the window
+    DroppedFile { path: PathBuf },
+    /// Sent when the dragged files have left the window without being dropped
+    HoveredFileCancelled,
+    /// Sent when a menu item is clicked or its accelerator is pressed
+    MenuItem { id: u16 },
+    /// Sent when the window has moved onto another monitor, e.g. to follow the refresh rate of the new one
+    MonitorChanged { monitor: MonitorId },
 }
 
 /// Specific keyboard events
@@ -130,6 +226,8 @@ pub enum KeyboardEvents {
     Key { keycode: usize, action: Action },
     /// Sent when a character is pressed. Difference between Key and Char events is that Char event is sensitive to the case of that key, while Key event is not!
     Char { keycode: usize },
+    /// Sent when a lock key (Key::CAPITAL, Key::NUMLOCK or Key::SCROLL) is turned on or off
+    ToggleChanged { keycode: usize, on: bool },
 }
 
 /// Specific mouse events
@@ -157,18 +255,45 @@ pub enum KeyboardEvents {
 /// ```
 #[derive(Debug, Copy, Clone, PartialEq)]
 pub enum MouseEvents {
-    /// Sent when a mouse is scrolling up or down
-    Scroll { y_offset: i16 },
+    /// Sent when a mouse is scrolling up or down. The modifiers are the ones which were held at the same time (e.g. Ctrl+Scroll for zooming)
+    Scroll { y_offset: i16, modifiers: Modifiers },
     /// Sent when a left mouse button is pressed, released or down
-    LButton { action: Action, pos: Point },
+    LButton {
+        action: Action,
+        pos: Point,
+        modifiers: Modifiers,
+    },
     /// Sent when a right mouse button is pressed, released or down
-    RButton { action: Action, pos: Point },
+    RButton {
+        action: Action,
+        pos: Point,
+        modifiers: Modifiers,
+    },
     /// Sent when a middle mouse button is pressed, released or down
-    MButton { action: Action, pos: Point },
+    MButton {
+        action: Action,
+        pos: Point,
+        modifiers: Modifiers,
+    },
     /// Sent when a x mouse button 1 is pressed, released or down
-    X1Button { action: Action, pos: Point },
+    X1Button {
+        action: Action,
+        pos: Point,
+        modifiers: Modifiers,
+    },
     /// Sent when a x mouse button 2 is pressed, released or down
-    X2Button { action: Action, pos: Point },
+    X2Button {
+        action: Action,
+        pos: Point,
+        modifiers: Modifiers,
+    },
+    /// Sent instead of the specific button variants when the MouseEventMode::Unified is set
+    Button {
+        button: MouseButton,
+        action: Action,
+        pos: Point,
+        modifiers: Modifiers,
+    },
     /// Sent when a cursor is moved from one point to another where x is new x position, y is new y position, last_x is last x position, last_y is last y position, dx is delta x (x - last_x) and dy is delta y (y - last_y)
     MouseMove {
         x: i16,
@@ -178,35 +303,154 @@ pub enum MouseEvents {
         dx: i16,
         dy: i16,
     },
+    /// Sent when the cursor leaves the client area of the window. The buttons which were held in the window and are not captured by it get their Release events before this one
+    Leave,
+}
+
+impl MouseEvents {
+    /// Returns the position of the cursor in the client area as fractions of the client size, so (0.5, 0.5) is the center whatever the size of the window is. It returns None for the events without a position and when the client area is empty (e.g. the window is minimized)
+    ///
+    /// # Example
+    ///
+    /// ```ignore
+    /// manager.run(|events, control_flow, manager| {
+    ///     match events {
+    ///         Events::MouseEvents { id: _, event } => {
+    ///             let client_size = manager.window().unwrap().client_rect_screen().size();
+    ///             if let Some((x, y)) = event.normalized_pos(client_size) {
+    ///                 ui.hover(x, y);
+    ///             }
+    ///         }
+    ///         _=> {}
+    ///     }
+    /// });
+    /// ```
+    pub fn normalized_pos(&self, client_size: Size) -> Option<(f32, f32)> {
+        let pos = match self {
+            MouseEvents::LButton { pos, .. }
+            | MouseEvents::RButton { pos, .. }
+            | MouseEvents::MButton { pos, .. }
+            | MouseEvents::X1Button { pos, .. }
+            | MouseEvents::X2Button { pos, .. }
+            | MouseEvents::Button { pos, .. } => *pos,
+            MouseEvents::MouseMove { x, y, .. } => Point::new(*x as i32, *y as i32),
+            MouseEvents::Scroll { .. } | MouseEvents::Leave => return None,
+        };
+
+        if client_size.width <= 0 || client_size.height <= 0 {
+            return None;
+        }
+
+        return Some((
+            pos.x as f32 / client_size.width as f32,
+            pos.y as f32 / client_size.height as f32,
+        ));
+    }
 }
 
-#[derive(Copy, Clone, Debug, PartialEq)]
+/// Mouse buttons of the MouseEvents::Button event
+///
+/// # Example
+///
+/// ```ignore
+/// manager.run(|events, control_flow, manager| {
+///     match events {
+///         Events::MouseEvents { id: _, event: MouseEvents::Button { button, action: Action::Press, pos, .. } } => {
+///             println!("{button:?} has been pressed at ({}, {})", pos.x, pos.y);
+///         }
+///         _=> {}
+///     }
+/// });
+/// ```
+#[derive(Debug, Copy, Clone, PartialEq, Eq, Hash, PartialOrd, Ord)]
+pub enum MouseButton {
+    /// Left mouse button
+    Left,
+    /// Right mouse button
+    Right,
+    /// Middle mouse button (the wheel)
+    Middle,
+    /// X mouse button 1 (usually the back button)
+    X1,
+    /// X mouse button 2 (usually the forward button)
+    X2,
+}
+
+impl MouseButton {
+    /// Returns the virtual code of the button which can be given to Manager::get_mouse_button()
+    ///
+    /// # Example
+    ///
+    /// ```ignore
+    /// assert_eq!(MouseButton::Right.code(), Button::RBUTTON);
+    /// ```
+    pub fn code(&self) -> usize {
+        return match self {
+            MouseButton::Left => Button::LBUTTON,
+            MouseButton::Right => Button::RBUTTON,
+            MouseButton::Middle => Button::MBUTTON,
+            MouseButton::X1 => Button::XBUTTON1,
+            MouseButton::X2 => Button::XBUTTON2,
+        };
+    }
+}
+
+#[derive(Clone, Debug, PartialEq)]
 pub(crate) enum MainEvents {
     MainWindowEvent {
-        id: usize,
+        id: WindowId,
         event: MainWindowEvents,
     },
     MainKeyboardEvent {
-        id: usize,
+        id: WindowId,
         event: MainKeyboardEvents,
     },
     MainMouseEvent {
-        id: usize,
+        id: WindowId,
         event: MainMouseEvents,
     },
+    MainRawMessage {
+        id: WindowId,
+        msg: u32,
+        wparam: usize,
+        lparam: isize,
+    },
+    MainJobFinished {
+        id: JobId,
+    },
+    MainError {
+        id: WindowId,
+        message: String,
+    },
 }
 
-#[derive(Copy, Clone, Debug, PartialEq)]
+#[derive(Clone, Debug, PartialEq)]
 pub(crate) enum MainWindowEvents {
-    Create,
+    Create {
+        size: Size,
+        position: Point,
+        scale_factor: f64,
+    },
+    CloseRequested,
     Close,
+    Destroyed,
     Maximized { width: i32, height: i32 },
     Minimized { width: i32, height: i32 },
     FramebufferChanged { width: i32, height: i32 },
+    ResizeStarted,
+    ResizeEnded { width: i32, height: i32 },
     Moved { x: i32, y: i32 },
     SetFocus,
     LostFocus,
-    RedrawRequested,
+    RedrawRequested {
+        dirty: Rect,
+    },
+    Timer { id: usize },
+    HoveredFile { path: PathBuf },
+    DroppedFile { path: PathBuf },
+    HoveredFileCancelled,
+    MenuItem { id: u16 },
+    MonitorChanged { monitor: MonitorId },
 }
 
 #[derive(Debug, Copy, Clone, PartialEq, Eq, PartialOrd, Ord)]
@@ -219,14 +463,39 @@ pub(crate) enum MainKeyboardEvents {
     Char {
         keycode: usize,
     },
+    Toggle {
+        keycode: usize,
+        on: bool,
+    },
 }
 
 #[derive(Debug, Copy, Clone, PartialEq)]
 pub(crate) enum MainMouseEvents {
-    Scroll { y_offset: i16 },
-    LButton { up: bool, pos: Point },
-    RButton { up: bool, pos: Point },
-    MButton { up: bool, pos: Point },
-    XButton { up: bool, wparam: u32, pos: Point },
+    Scroll {
+        y_offset: i16,
+        modifiers: Modifiers,
+    },
+    LButton {
+        up: bool,
+        pos: Point,
+        modifiers: Modifiers,
+    },
+    RButton {
+        up: bool,
+        pos: Point,
+        modifiers: Modifiers,
+    },
+    MButton {
+        up: bool,
+        pos: Point,
+        modifiers: Modifiers,
+    },
+    XButton {
+        up: bool,
+        wparam: u32,
+        pos: Point,
+        modifiers: Modifiers,
+    },
     MouseMove { x: i16, y: i16 },
+    Leave,
 }
@@ -0,0 +1,237 @@
+use std::{
+    ffi::OsString,
+    os::windows::ffi::OsStringExt,
+    path::PathBuf,
+    sync::{
+        atomic::{AtomicUsize, Ordering},
+        Arc, Mutex,
+    },
+};
+
+use winapi::{
+    ctypes::c_void,
+    shared::{
+        guiddef::{IsEqualGUID, REFIID},
+        minwindef::{DWORD, UINT, ULONG},
+        windef::POINTL,
+        winerror::{E_NOINTERFACE, HRESULT, S_OK, SUCCEEDED},
+        wtypes::{CLIPFORMAT, DVASPECT_CONTENT},
+    },
+    um::{
+        objidl::{IDataObject, FORMATETC, STGMEDIUM, TYMED_HGLOBAL},
+        oleidl::{
+            IDropTarget, IDropTargetVtbl, DROPEFFECT_COPY, DROPEFFECT_LINK, DROPEFFECT_MOVE,
+            DROPEFFECT_NONE,
+        },
+        shellapi::{DragFinish, DragQueryFileW, HDROP},
+        unknwnbase::{IUnknown, IUnknownVtbl},
+        winuser::CF_HDROP,
+    },
+    Interface,
+};
+
+use crate::prelude::*;
+
+// winapi does not declare OleUninitialize
+#[link(name = "ole32")]
+extern "system" {
+    pub(crate) fn OleUninitialize();
+}
+
+#[repr(C)]
+pub(crate) struct FileDropHandler {
+    vtbl: *const IDropTargetVtbl,
+    refcount: AtomicUsize,
+    id: WindowId,
+    msger: Messenger,
+    state: Arc<Mutex<WindowState>>,
+    hovered_files: bool,
+}
+
+static FILE_DROP_HANDLER_VTBL: IDropTargetVtbl = IDropTargetVtbl {
+    parent: IUnknownVtbl {
+        QueryInterface: FileDropHandler::query_interface,
+        AddRef: FileDropHandler::add_ref,
+        Release: FileDropHandler::release,
+    },
+    DragEnter: FileDropHandler::drag_enter,
+    DragOver: FileDropHandler::drag_over,
+    DragLeave: FileDropHandler::drag_leave,
+    Drop: FileDropHandler::drop,
+};
+
+impl FileDropHandler {
+    /// Creates the handler as a COM object with one reference which is given up with Release()
+    pub(crate) fn create(
+        id: WindowId,
+        msger: Messenger,
+        state: Arc<Mutex<WindowState>>,
+    ) -> *mut IDropTarget {
+        let handler = Box::new(Self {
+            vtbl: &FILE_DROP_HANDLER_VTBL,
+            refcount: AtomicUsize::new(1),
+            id,
+            msger,
+            state,
+            hovered_files: false,
+        });
+
+        return Box::into_raw(handler) as *mut IDropTarget;
+    }
+
+    unsafe fn from_interface<'a, T>(this: *mut T) -> &'a mut Self {
+        return (this as *mut Self).as_mut().unwrap();
+    }
+
+    unsafe extern "system" fn query_interface(
+        this: *mut IUnknown,
+        riid: REFIID,
+        ppv_object: *mut *mut c_void,
+    ) -> HRESULT {
+        if IsEqualGUID(&*riid, &IUnknown::uuidof()) || IsEqualGUID(&*riid, &IDropTarget::uuidof()) {
+            Self::add_ref(this);
+            *ppv_object = this as *mut c_void;
+            return S_OK;
+        }
+
+        *ppv_object = std::ptr::null_mut();
+        return E_NOINTERFACE;
+    }
+
+    unsafe extern "system" fn add_ref(this: *mut IUnknown) -> ULONG {
+        let handler = Self::from_interface(this);
+        return (handler.refcount.fetch_add(1, Ordering::Release) + 1) as ULONG;
+    }
+
+    unsafe extern "system" fn release(this: *mut IUnknown) -> ULONG {
+        let handler = Self::from_interface(this);
+        let count = handler.refcount.fetch_sub(1, Ordering::Release) - 1;
+        if count == 0 {
+            drop(Box::from_raw(this as *mut Self));
+        }
+
+        return count as ULONG;
+    }
+
+    unsafe extern "system" fn drag_enter(
+        this: *mut IDropTarget,
+        data_obj: *const IDataObject,
+        _key_state: DWORD,
+        _pt: *const POINTL,
+        effect: *mut DWORD,
+    ) -> HRESULT {
+        let handler = Self::from_interface(this);
+        let id = handler.id;
+        let msger = &handler.msger;
+
+        handler.hovered_files = Self::iterate_filenames(data_obj, |path| {
+            msger.send(MainEvents::MainWindowEvent {
+                id,
+                event: MainWindowEvents::HoveredFile { path },
+            });
+        });
+
+        *effect = handler.effect();
+        return S_OK;
+    }
+
+    unsafe extern "system" fn drag_over(
+        this: *mut IDropTarget,
+        _key_state: DWORD,
+        _pt: *const POINTL,
+        effect: *mut DWORD,
+    ) -> HRESULT {
+        let handler = Self::from_interface(this);
+        *effect = handler.effect();
+        return S_OK;
+    }
+
+    unsafe extern "system" fn drag_leave(this: *mut IDropTarget) -> HRESULT {
+        let handler = Self::from_interface(this);
+        if handler.hovered_files {
+            handler.msger.send(MainEvents::MainWindowEvent {
+                id: handler.id,
+                event: MainWindowEvents::HoveredFileCancelled,
+            });
+        }
+
+        handler.hovered_files = false;
+        return S_OK;
+    }
+
+    unsafe extern "system" fn drop(
+        this: *mut IDropTarget,
+        data_obj: *const IDataObject,
+        _key_state: DWORD,
+        _pt: *const POINTL,
+        effect: *mut DWORD,
+    ) -> HRESULT {
+        let handler = Self::from_interface(this);
+        let id = handler.id;
+        let msger = &handler.msger;
+
+        if handler.hovered_files {
+            Self::iterate_filenames(data_obj, |path| {
+                msger.send(MainEvents::MainWindowEvent {
+                    id,
+                    event: MainWindowEvents::DroppedFile { path },
+                });
+            });
+        }
+
+        *effect = handler.effect();
+        handler.hovered_files = false;
+        return S_OK;
+    }
+
+    fn effect(&self) -> DWORD {
+        if !self.hovered_files {
+            return DROPEFFECT_NONE;
+        }
+
+        return match self.state.lock().unwrap().drop_effect {
+            DropEffect::None => DROPEFFECT_NONE,
+            DropEffect::Copy => DROPEFFECT_COPY,
+            DropEffect::Move => DROPEFFECT_MOVE,
+            DropEffect::Link => DROPEFFECT_LINK,
+        };
+    }
+
+    unsafe fn iterate_filenames<F>(data_obj: *const IDataObject, mut callback: F) -> bool
+    where
+        F: FnMut(PathBuf),
+    {
+        let format = FORMATETC {
+            cfFormat: CF_HDROP as CLIPFORMAT,
+            ptd: std::ptr::null(),
+            dwAspect: DVASPECT_CONTENT,
+            lindex: -1,
+            tymed: TYMED_HGLOBAL,
+        };
+
+        let mut medium: STGMEDIUM = std::mem::zeroed();
+        if !SUCCEEDED((*data_obj).GetData(&format, &mut medium)) {
+            return false;
+        }
+
+        // winapi declares the union of STGMEDIUM as a pointer, its value is the HGLOBAL itself
+        let hdrop = medium.u as HDROP;
+        let count = DragQueryFileW(hdrop, 0xFFFFFFFF, std::ptr::null_mut(), 0);
+        for i in 0..count {
+            let len = DragQueryFileW(hdrop, i, std::ptr::null_mut(), 0) as usize;
+            let mut path = vec![0u16; len + 1];
+            DragQueryFileW(hdrop, i, path.as_mut_ptr(), path.len() as UINT);
+            path.truncate(len);
+
+            callback(PathBuf::from(OsString::from_wide(&path)));
+        }
+
+        if medium.pUnkForRelease.is_null() {
+            DragFinish(hdrop);
+        } else {
+            (*medium.pUnkForRelease).Release();
+        }
+
+        return true;
+    }
+}
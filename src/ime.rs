@@ -0,0 +1,64 @@
+use winapi::{
+    shared::{
+        minwindef::{BOOL, DWORD},
+        windef::{HWND, POINT, RECT},
+    },
+    um::imm::{
+        ImmGetContext, ImmReleaseContext, ImmSetCompositionWindow, CFS_EXCLUDE, CFS_RECT,
+        COMPOSITIONFORM, HIMC,
+    },
+};
+
+use crate::prelude::*;
+
+// winapi does not declare the candidate window api
+#[repr(C)]
+#[allow(non_snake_case, clippy::upper_case_acronyms)]
+struct CANDIDATEFORM {
+    dwIndex: DWORD,
+    dwStyle: DWORD,
+    ptCurrentPos: POINT,
+    rcArea: RECT,
+}
+
+#[link(name = "imm32")]
+extern "system" {
+    fn ImmSetCandidateWindow(himc: HIMC, candidate: *mut CANDIDATEFORM) -> BOOL;
+}
+
+// Must be called on the thread of the window, the input context cannot be reached from the others
+pub(crate) unsafe fn set_text_input_area(hwnd: HWND, area: Rect) {
+    let himc = ImmGetContext(hwnd);
+    if himc.is_null() {
+        return;
+    }
+
+    let rect = RECT {
+        left: area.left,
+        top: area.top,
+        right: area.right,
+        bottom: area.bottom,
+    };
+    let pos = POINT {
+        x: area.left,
+        y: area.top,
+    };
+
+    let mut composition = COMPOSITIONFORM {
+        dwStyle: CFS_RECT,
+        ptCurrentPos: pos,
+        rcArea: rect,
+    };
+    ImmSetCompositionWindow(himc, &mut composition);
+
+    // The candidate list is placed next to the area without covering it
+    let mut candidate = CANDIDATEFORM {
+        dwIndex: 0,
+        dwStyle: CFS_EXCLUDE,
+        ptCurrentPos: pos,
+        rcArea: rect,
+    };
+    ImmSetCandidateWindow(himc, &mut candidate);
+
+    ImmReleaseContext(hwnd, himc);
+}
@@ -0,0 +1,253 @@
+use winapi::{
+    shared::minwindef::WPARAM,
+    um::winuser::{GetKeyState, MK_CONTROL, MK_SHIFT, VK_CONTROL, VK_MENU, VK_SHIFT},
+};
+
+use crate::prelude::*;
+
+/// State of the mouse at the moment the snapshot has been taken
+///
+/// # Example
+///
+/// ```ignore
+/// let snapshot = manager.snapshot_input();
+/// println!("Cursor is at ({}, {})", snapshot.mouse.x, snapshot.mouse.y);
+/// ```
+#[derive(Default, Debug, Copy, Clone, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct MouseState {
+    /// Current x position of the cursor
+    pub x: i16,
+    /// Current y position of the cursor
+    pub y: i16,
+    /// Last x position of the cursor
+    pub last_x: i16,
+    /// Last y position of the cursor
+    pub last_y: i16,
+    /// State of the left mouse button
+    pub l_button: Action,
+    /// State of the right mouse button
+    pub r_button: Action,
+    /// State of the middle mouse button
+    pub m_button: Action,
+    /// State of the x mouse button 1
+    pub x1_button: Action,
+    /// State of the x mouse button 2
+    pub x2_button: Action,
+}
+
+/// The raw state of a key behind its Action. Unlike the Action it tells that a key has been pressed in this frame and is still down at the same time
+///
+/// # Example
+///
+/// ```ignore
+/// let shift = manager.key_info(Key::SHIFT);
+/// if shift.is_down() && shift.just_pressed() {
+///     println!("Shift has just been pressed");
+/// }
+/// ```
+#[derive(Default, Debug, Copy, Clone, PartialEq, Eq, Hash)]
+pub struct KeyInfo {
+    is_down: bool,
+    just_pressed: bool,
+    just_released: bool,
+}
+
+impl KeyInfo {
+    pub(crate) fn from(keyboard: &Keyboard, keycode: usize) -> Self {
+        let is_down = keyboard.is_down(keycode);
+        let is_changed = keyboard.is_changed(keycode);
+
+        return Self {
+            is_down,
+            just_pressed: is_down && is_changed,
+            just_released: !is_down && is_changed,
+        };
+    }
+
+    /// Returns whether the key is held
+    ///
+    /// # Example
+    ///
+    /// ```ignore
+    /// if manager.key_info(Key::W).is_down() {
+    ///     player.walk();
+    /// }
+    /// ```
+    pub fn is_down(&self) -> bool {
+        return self.is_down;
+    }
+
+    /// Returns whether the key has been pressed since the last event
+    ///
+    /// # Example
+    ///
+    /// ```ignore
+    /// if manager.key_info(Key::SPACE).just_pressed() {
+    ///     player.jump();
+    /// }
+    /// ```
+    pub fn just_pressed(&self) -> bool {
+        return self.just_pressed;
+    }
+
+    /// Returns whether the key has been released since the last event
+    ///
+    /// # Example
+    ///
+    /// ```ignore
+    /// if manager.key_info(Key::SPACE).just_released() {
+    ///     player.stop_charging();
+    /// }
+    /// ```
+    pub fn just_released(&self) -> bool {
+        return self.just_released;
+    }
+}
+
+/// A snapshot of the whole input state (keyboard and mouse) which can be recorded every frame and played back later with Manager::apply_snapshot()
+///
+/// # Example
+///
+/// ```ignore
+/// let mut replay: Vec<InputSnapshot> = Vec::new();
+///
+/// manager.run(|events, control_flow, manager| {
+///     replay.push(manager.snapshot_input());
+/// });
+/// ```
+#[derive(Default, Debug, Clone, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct InputSnapshot {
+    /// Keycodes with their states. Keys which are idle (Action::None) are not stored
+    pub keys: Vec<(usize, Action)>,
+    /// State of the mouse
+    pub mouse: MouseState,
+}
+
+impl InputSnapshot {
+    /// Returns the recorded state of the key. If the key is not in the snapshot, Action::None is returned
+    ///
+    /// # Example
+    ///
+    /// ```ignore
+    /// let snapshot = manager.snapshot_input();
+    /// if snapshot.get_key(Key::SPACE) == Action::Press {
+    ///     println!("Space was pressed in this frame");
+    /// }
+    /// ```
+    pub fn get_key(&self, keycode: usize) -> Action {
+        return self
+            .keys
+            .iter()
+            .find(|(key, _)| *key == keycode)
+            .map(|(_, action)| *action)
+            .unwrap_or(Action::None);
+    }
+}
+
+/// The input of one frame bundled for an immediate mode GUI (e.g. it maps one to one onto the RawInput of egui). It is taken with Manager::raw_input() once per frame
+///
+/// # Example
+///
+/// ```ignore
+/// manager.enable_text_buffer(true);
+///
+/// manager.run(|events, control_flow, manager| {
+///     let input = manager.raw_input();
+///     gui.begin_frame(input.mouse.x, input.mouse.y, input.scroll_delta, &input.text);
+/// });
+/// ```
+#[derive(Default, Debug, Clone, PartialEq, Eq)]
+pub struct RawInput {
+    /// Modifiers which are held at the moment
+    pub modifiers: Modifiers,
+    /// Position and buttons of the mouse
+    pub mouse: MouseState,
+    /// Number of the wheel notches scrolled since the last call (positive is up)
+    pub scroll_delta: i32,
+    /// Characters typed since the last call. It is always empty if the text buffer has not been enabled with Manager::enable_text_buffer()
+    pub text: String,
+    /// Keycodes with their states. Keys which are idle (Action::None) are not stored
+    pub keys: Vec<(usize, Action)>,
+    /// The window which has the keyboard focus
+    pub focused: Option<WindowId>,
+}
+
+/// Decides which keyboard events are sent by the Manager
+///
+/// # Example
+///
+/// ```ignore
+/// manager.set_key_event_mode(KeyEventMode::Transitions);
+/// ```
+#[derive(Default, Debug, Copy, Clone, PartialEq, Eq)]
+pub enum KeyEventMode {
+    /// KeyboardEvents::Key is sent on every key message, so Action::Down is sent again and again while a key is held
+    #[default]
+    Continuous,
+    /// KeyboardEvents::Key is sent only when the state of a key really changes: Action::Press when it goes down and Action::Release when it goes up
+    Transitions,
+}
+
+/// Decides how the mouse button events are sent by the Manager
+///
+/// # Example
+///
+/// ```ignore
+/// manager.set_mouse_event_mode(MouseEventMode::Unified);
+/// ```
+#[derive(Default, Debug, Copy, Clone, PartialEq, Eq)]
+pub enum MouseEventMode {
+    /// Every button has its own variant: MouseEvents::LButton, MouseEvents::RButton, MouseEvents::MButton, MouseEvents::X1Button and MouseEvents::X2Button
+    #[default]
+    Specific,
+    /// All the buttons are sent as MouseEvents::Button { button, action, pos, modifiers } so that they can be handled in one match arm
+    Unified,
+}
+
+/// Modifier keys which were held when a mouse event happened
+///
+/// # Example
+///
+/// ```ignore
+/// manager.run(|events, control_flow, manager| {
+///     match events {
+///         Events::MouseEvents { id: _, event: MouseEvents::Scroll { y_offset, modifiers } } => {
+///             if modifiers.control {
+///                 zoom += y_offset as f32 * 0.1;
+///             }
+///         }
+///         _=> {}
+///     }
+/// });
+/// ```
+#[derive(Default, Debug, Copy, Clone, PartialEq, Eq, Hash)]
+pub struct Modifiers {
+    /// Whether a Ctrl key was held
+    pub control: bool,
+    /// Whether a Shift key was held
+    pub shift: bool,
+    /// Whether an Alt key was held
+    pub alt: bool,
+}
+
+impl Modifiers {
+    // Must be called on the window thread while the message is processed so that GetKeyState() matches the message
+    pub(crate) unsafe fn from_wparam(wparam: WPARAM) -> Self {
+        return Self {
+            control: wparam & MK_CONTROL != 0,
+            shift: wparam & MK_SHIFT != 0,
+            alt: GetKeyState(VK_MENU) < 0,
+        };
+    }
+
+    // For the messages which do not carry the MK_* flags
+    pub(crate) unsafe fn current() -> Self {
+        return Self {
+            control: GetKeyState(VK_CONTROL) < 0,
+            shift: GetKeyState(VK_SHIFT) < 0,
+            alt: GetKeyState(VK_MENU) < 0,
+        };
+    }
+}
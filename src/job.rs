@@ -0,0 +1,169 @@
+use std::sync::{
+    atomic::{AtomicU64, Ordering},
+    mpsc::{channel, Receiver, Sender},
+    Arc, Mutex, OnceLock,
+};
+
+use crate::prelude::*;
+
+/// An id of a job which has been started with Manager::spawn(). It is given back by the Events::JobFinished event
+///
+/// # Example
+///
+/// ```ignore
+/// let job = manager.spawn(|| load_level("level1.map"));
+/// let job_id = job.id();
+///
+/// manager.run(move |events, control_flow, manager| {
+///     match events {
+///         Events::JobFinished { id } if id == job_id => println!("The level has been loaded"),
+///         _=> {}
+///     }
+/// });
+/// ```
+#[derive(Default, Debug, Copy, Clone, PartialEq, Eq, Hash, PartialOrd, Ord)]
+pub struct JobId(pub(crate) u64);
+
+impl JobId {
+    pub(crate) fn next() -> Self {
+        static NEXT_ID: AtomicU64 = AtomicU64::new(1);
+        return Self(NEXT_ID.fetch_add(1, Ordering::Relaxed));
+    }
+
+    /// Returns the id as a number
+    ///
+    /// # Example
+    ///
+    /// ```ignore
+    /// let id: u64 = job.id().as_u64();
+    /// ```
+    pub fn as_u64(&self) -> u64 {
+        return self.0;
+    }
+}
+
+impl std::fmt::Display for JobId {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}", self.0)
+    }
+}
+
+type Task = Box<dyn FnOnce() + Send + 'static>;
+
+// The jobs of every Manager share one fixed set of worker threads (one per CPU core) which is started by the first job
+fn workers() -> &'static Sender<Task> {
+    static WORKERS: OnceLock<Sender<Task>> = OnceLock::new();
+
+    return WORKERS.get_or_init(|| {
+        let (sender, receiver) = channel::<Task>();
+        let receiver = Arc::new(Mutex::new(receiver));
+        let count = std::thread::available_parallelism().map(|count| count.get()).unwrap_or(4);
+
+        for index in 0..count {
+            let receiver = receiver.clone();
+            std::thread::Builder::new()
+                .name(format!("dgews-job-{index}"))
+                .spawn(move || loop {
+                    // The lock is released before the task runs, so the other workers can take the next tasks
+                    let task = receiver.lock().unwrap().recv();
+                    match task {
+                        // Nothing a task does may take its worker down, the pool never replaces one
+                        Ok(task) => {
+                            let _ = std::panic::catch_unwind(std::panic::AssertUnwindSafe(task));
+                        }
+                        Err(_) => return,
+                    }
+                })
+                .expect("a job worker thread could not be started");
+        }
+
+        sender
+    });
+}
+
+/// A handle to the result of a job which runs on one of the worker threads. The result can be polled every frame or waited for
+///
+/// # Example
+///
+/// ```ignore
+/// let mut job = manager.spawn(|| compute_navmesh(&level));
+///
+/// manager.run(move |events, control_flow, manager| {
+///     if let Some(navmesh) = job.poll() {
+///         println!("The navmesh has {} polygons", navmesh.len());
+///     }
+/// });
+/// ```
+#[derive(Debug)]
+pub struct JobHandle<T> {
+    id: JobId,
+    receiver: Receiver<T>,
+}
+
+impl<T: Send + 'static> JobHandle<T> {
+    pub(crate) fn spawn<F>(msger: Messenger, func: F) -> Self
+    where
+        F: FnOnce() -> T + Send + 'static,
+    {
+        let id = JobId::next();
+        let (sender, receiver) = channel();
+
+        let task: Task = Box::new(move || {
+            // The result is sent before the event so that poll() returns it as soon as the event arrives
+            match std::panic::catch_unwind(std::panic::AssertUnwindSafe(func)) {
+                Ok(value) => {
+                    // The handle may have been dropped already
+                    let _ = sender.send(value);
+                    // The Manager may have been dropped as well
+                    msger.try_send(MainEvents::MainJobFinished { id });
+                }
+                // The panic hook has reported it already. The worker keeps running and the dropped sender makes wait() panic
+                Err(_) => {
+                    drop(sender);
+                    msger.try_send(MainEvents::MainJobFinished { id });
+                }
+            }
+        });
+        workers().send(task).expect("the job worker threads have stopped");
+
+        return Self { id, receiver };
+    }
+}
+
+impl<T> JobHandle<T> {
+    /// Returns the id which the Events::JobFinished event of this job carries
+    ///
+    /// # Example
+    ///
+    /// ```ignore
+    /// println!("Job {} has been started", job.id());
+    /// ```
+    pub fn id(&self) -> JobId {
+        return self.id;
+    }
+
+    /// Returns the result if the job has finished, otherwise returns None without blocking. The result is given only once
+    ///
+    /// # Example
+    ///
+    /// ```ignore
+    /// if let Some(texture) = job.poll() {
+    ///     textures.push(texture);
+    /// }
+    /// ```
+    pub fn poll(&mut self) -> Option<T> {
+        return self.receiver.try_recv().ok();
+    }
+
+    /// Blocks until the job has finished and returns its result. It panics if the job has panicked or its result has already been taken with poll()
+    ///
+    /// # Example
+    ///
+    /// ```ignore
+    /// let job = manager.spawn(|| 6 * 7);
+    /// assert_eq!(job.wait(), 42);
+    /// ```
+    pub fn wait(self) -> T {
+        return self.receiver.recv().expect("the job has panicked or its result has been taken");
+    }
+}
@@ -21,7 +21,8 @@ impl Keyboard {
         let mut chars = HashMap::new();
 
         for i in 0..n_keys {
-            keys.insert(i, KeyState::new(false, false, false));
+            // A key which has never been pressed counts as released, so its first press is sent
+            keys.insert(i, KeyState::new(false, true, false));
             chars.insert(i, false);
         }
 
@@ -43,6 +44,18 @@ impl Keyboard {
         *self.keys.get_mut(&key).unwrap() = KeyState::new(is_down, is_released, is_changed);
     }
 
+    pub(crate) fn key_state(&self, keycode: usize) -> KeyState {
+        return *self.keys.get(&keycode).unwrap();
+    }
+
+    pub(crate) fn set_key_state(&mut self, keycode: usize, state: KeyState) {
+        *self.keys.get_mut(&keycode).unwrap() = state;
+    }
+
+    pub(crate) fn n_keys(&self) -> usize {
+        return self.n_keys;
+    }
+
     pub(crate) fn is_down(&self, keycode: usize) -> bool {
         return self.keys.get(&keycode).unwrap().is_down();
     }
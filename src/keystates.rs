@@ -1,3 +1,5 @@
+use crate::prelude::*;
+
 #[derive(Default, Debug, Copy, Clone, PartialEq, Eq, PartialOrd, Ord)]
 pub(crate) struct KeyState {
     is_down: bool,
@@ -38,4 +40,25 @@ impl KeyState {
     pub(crate) fn set_changed(&mut self, value: bool) {
         self.is_changed = value;
     }
+
+    pub(crate) fn action(&self) -> Action {
+        return if self.is_down && !self.is_changed {
+            Action::Down
+        } else if self.is_down && self.is_changed {
+            Action::Press
+        } else if !self.is_down && self.is_changed {
+            Action::Release
+        } else {
+            Action::None
+        };
+    }
+
+    pub(crate) fn from_action(action: Action) -> Self {
+        return match action {
+            Action::Press => Self::new(true, false, true),
+            Action::Down => Self::new(true, false, false),
+            Action::Release => Self::new(false, true, true),
+            Action::None => Self::new(false, true, false),
+        };
+    }
 }
@@ -17,11 +17,11 @@
 //!     manager.run(|events, control_flow, manager| {
 //!         match events {
 //!             Events::WindowEvent { id, event } => match event {
-//!                 WindowEvents::Create => println!("[INFO]: a new window with id: {} has been created", manager.window().get_id()),
+//!                 WindowEvents::Create { .. } => println!("[INFO]: a new window with id: {} has been created", manager.window().get_id()),
 //! 
 //!                 WindowEvents::Close => {
 //!                     println!("[INFO]: a window with id: {} has been closed", manager.window().get_id());
-//!                     *control_flow => ControlFlow::Exit; // to exit with panicing, use ControlFlow::ExitWithCode(<your number>) instead.
+//!                     *control_flow => ControlFlow::Exit; // to exit with a code, use ControlFlow::ExitWithCode(<your number>) and Manager::exit_code() instead.
 //!                 },
 //! 
 //!                 _=> {}
@@ -46,29 +46,51 @@
 //! }
 //! ```
 
+pub(crate) mod filedrop;
+pub(crate) mod ime;
 pub(crate) mod keyboard;
 pub(crate) mod keystates;
 pub(crate) mod mouse;
+pub(crate) mod translate;
+pub(crate) mod windowstate;
 
 pub mod common;
 pub mod controlflow;
+pub mod ease;
+pub mod error;
 pub mod events;
+pub mod input;
+pub mod job;
 pub mod keycodes;
 pub mod manager;
+pub mod managerbuilder;
+pub mod menu;
+pub mod metrics;
 pub mod timer;
 pub mod window;
 pub mod windowbuilder;
 
 pub mod prelude {
+    pub(crate) use super::filedrop::*;
+    pub(crate) use super::ime::*;
     pub(crate) use super::keyboard::*;
     pub(crate) use super::keystates::*;
     pub(crate) use super::mouse::*;
+    pub(crate) use super::translate::*;
+    pub(crate) use super::windowstate::*;
     
     pub use super::common::*;
     pub use super::controlflow::*;
+    pub use super::ease;
+    pub use super::error::*;
     pub use super::events::*;
+    pub use super::input::*;
+    pub use super::job::*;
     pub use super::keycodes::*;
     pub use super::manager::*;
+    pub use super::managerbuilder::*;
+    pub use super::menu::*;
+    pub use super::metrics::*;
     pub use super::timer::*;
     pub use super::window::*;
     pub use super::windowbuilder::*;
@@ -3,7 +3,7 @@
 use std::{
     collections::HashMap,
     sync::{
-        mpsc::{Receiver, Sender},
+        mpsc::{Receiver, RecvTimeoutError, Sender},
         Arc, Mutex,
     },
 };
@@ -11,11 +11,22 @@ use std::{
 use winapi::{
     ctypes::*,
     shared::{minwindef::*, windef::*},
-    um::{wingdi::MAKEPOINTS, winuser::*},
+    um::{
+        libloaderapi::GetModuleHandleW,
+        ole2::{OleInitialize, RegisterDragDrop, RevokeDragDrop},
+        wingdi::MAKEPOINTS,
+        winuser::*,
+    },
 };
 
 use crate::prelude::*;
 
+// winapi does not declare the app user model id api
+#[link(name = "shell32")]
+extern "system" {
+    fn SetCurrentProcessExplicitAppUserModelID(app_id: *const u16) -> i32;
+}
+
 /// Central point of this crate. A Manager processes the events and messages of every window. It gives some miscellaneous information as well such as the time.
 ///
 /// # Example
@@ -44,25 +55,55 @@ use crate::prelude::*;
 #[derive(Debug)]
 pub struct Manager {
     windows: HashMap<String, Window>,
-    mouse: Mouse,
-    keyboard: Keyboard,
+    threads: HashMap<WindowId, std::thread::JoinHandle<()>>,
+    input: InputState,
     timer: Timer,
     msger: Messenger,
+    exit_on_last_window: bool,
+    default_cursor: Option<Cursor>,
+    min_size: Option<Size>,
+    raw_messages: bool,
+    frame_rate_limit: Option<f32>,
+    wait_timeout: Option<std::time::Duration>,
+    // Owned by set_frame_rate_limit(), which may drop it
+    frame_limit_resolution: Option<HighResolutionTimer>,
+    // Owned by ManagerBuilder::with_high_resolution_timer(), kept as long as the Manager lives
+    builder_resolution: Option<HighResolutionTimer>,
+    event_tap: Option<EventTap>,
     close: bool,
+    exit_code: Option<u32>,
     sender: Sender<Events>,
     receiver: Receiver<Events>,
 }
 
+struct EventTap(Box<dyn FnMut(&Events) + Send>);
+
+impl std::fmt::Debug for EventTap {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "EventTap")
+    }
+}
+
 impl Default for Manager {
     fn default() -> Self {
         let (sender, receiver) = std::sync::mpsc::channel();
         return Self {
-            mouse: Mouse::new(),
+            input: InputState::new(),
             timer: Timer::new(),
-            keyboard: Keyboard::new(false),
             windows: HashMap::default(),
+            threads: HashMap::default(),
             msger: Messenger::new(),
+            exit_on_last_window: true,
+            default_cursor: None,
+            min_size: None,
+            raw_messages: false,
+            frame_rate_limit: None,
+            wait_timeout: None,
+            frame_limit_resolution: None,
+            builder_resolution: None,
+            event_tap: None,
             close: false,
+            exit_code: None,
             sender,
             receiver,
         };
@@ -72,8 +113,10 @@ impl Default for Manager {
 impl Manager {
     #[allow(non_upper_case_globals)]
     const DGEWindowClassExWName: &'static str = "DGEWindowClassExWName";
+    // Creating a window takes milliseconds, so this is only hit when its thread is stuck
+    const CREATION_TIMEOUT: std::time::Duration = std::time::Duration::from_secs(10);
 
-    /// Creates a new instance of the Window Manager. Unlikely to default(), you have to give one WindowBuilder sturct.
+    /// Creates a new instance of the Window Manager. Unlikely to default(), you have to give one WindowBuilder sturct. Panics if the window cannot be created, use try_new() to handle that
     ///
     /// # Example
     ///
@@ -85,12 +128,99 @@ impl Manager {
     ///     .with_icon("path\\to\\your\\icon\\.ico"));
     /// ```
     pub fn new(builder: WindowBuilder) -> Self {
+        return match Self::try_new(builder) {
+            Ok(manager) => manager,
+            Err(error) => panic!("{error}"),
+        };
+    }
+
+    /// Creates a new instance of the Window Manager like new() but returns the error if the WindowBuilder is invalid (see WindowBuilder::validate()), its icon cannot be loaded, the class of the window cannot be registered, the window cannot be created or its thread does not report back in time
+    ///
+    /// # Example
+    ///
+    /// ```ignore
+    /// let manager = match Manager::try_new(WindowBuilder::default()) {
+    ///     Ok(manager) => manager,
+    ///     Err(error) => {
+    ///         eprintln!("[ERROR]: {error}");
+    ///         return;
+    ///     }
+    /// };
+    /// ```
+    pub fn try_new(builder: WindowBuilder) -> Result<Self, WindowError> {
         let mut manager = Manager::default();
-        manager.insert(Self::DGEWindowClassExWName, builder);
-        return manager;
+        manager.insert(Self::DGEWindowClassExWName, builder)?;
+        return Ok(manager);
+    }
+
+    /// Returns a ManagerBuilder to configure the process and the event loop before the first window is created
+    ///
+    /// # Example
+    ///
+    /// ```ignore
+    /// let manager = Manager::builder()
+    ///     .with_dpi_aware(true)
+    ///     .with_high_resolution_timer(true)
+    ///     .build(WindowBuilder::default())?;
+    /// ```
+    pub fn builder() -> ManagerBuilder {
+        return ManagerBuilder::new();
+    }
+
+    pub(crate) fn from_builder(
+        manager_builder: ManagerBuilder,
+        builder: WindowBuilder,
+    ) -> Result<Self, WindowError> {
+        // The process settings have to be applied before the first window is created
+        if manager_builder.dpi_aware {
+            unsafe {
+                SetProcessDPIAware();
+            }
+        }
+        if let Some(id) = manager_builder.app_user_model_id.as_ref() {
+            Self::set_app_user_model_id(id)?;
+        }
+
+        let mut manager = Manager::default();
+        manager.set_frame_rate_limit(manager_builder.frame_rate_limit);
+        // Kept apart from the guard of the frame rate limit, which set_frame_rate_limit(None) drops
+        if manager_builder.high_resolution_timer {
+            manager.builder_resolution = Some(HighResolutionTimer::begin());
+        }
+        manager.wait_timeout = manager_builder.wait_timeout;
+        manager.exit_on_last_window = manager_builder.exit_on_last_window;
+        manager.default_cursor = manager_builder.default_cursor;
+        manager.min_size = manager_builder.min_size;
+        manager.input.key_event_mode = manager_builder.key_event_mode;
+        manager.input.mouse_event_mode = manager_builder.mouse_event_mode;
+        manager.raw_messages = manager_builder.raw_messages;
+
+        manager.insert(Self::DGEWindowClassExWName, builder)?;
+        return Ok(manager);
+    }
+
+    /// Sets the AppUserModelID of the process ("Company.Product" by convention, at most 128 characters). The taskbar groups the windows, pins them and attributes the toast notifications by this id instead of by the executable. It has to be called before the manager creates any window since the windows which already exist keep their group
+    ///
+    /// # Example
+    ///
+    /// ```ignore
+    /// Manager::set_app_user_model_id("MrTitanHearted.DGEWS.Demo").unwrap();
+    /// let manager = Manager::new(WindowBuilder::default());
+    /// ```
+    pub fn set_app_user_model_id(id: &str) -> Result<(), WindowError> {
+        let app_id = Wstring::from(id);
+        let code = unsafe { SetCurrentProcessExplicitAppUserModelID(app_id.as_ptr()) };
+        if code < 0 {
+            return Err(WindowError::AppUserModelId {
+                id: id.to_string(),
+                code,
+            });
+        }
+
+        return Ok(());
     }
 
-    /// Inserts a new window. You have to give each new extra window a class which is basically the same as 'key' in HashMap<T>. There should be no white spaces.
+    /// Inserts a new window. You have to give each new extra window a class which is basically the same as 'key' in HashMap<T>. There should be no white spaces. Panics if the window cannot be created, use try_add_window() to handle that
     ///
     /// # Example
     ///
@@ -103,46 +233,116 @@ impl Manager {
     ///         .with_title("Another tiny one as well")
     ///         .with_dimensions(60, 60));
     /// ```
-    pub fn add_window(mut self, class: &str, builder: WindowBuilder) -> Self {
-        self.insert(class, builder);
-        return self;
+    pub fn add_window(self, class: &str, builder: WindowBuilder) -> Self {
+        return match self.try_add_window(class, builder) {
+            Ok(manager) => manager,
+            Err(error) => panic!("{error}"),
+        };
+    }
+
+    /// Inserts a new window like add_window() but returns the error instead of panicking, e.g. when the class is already used by another window or the WindowBuilder is invalid
+    ///
+    /// # Example
+    ///
+    /// ```ignore
+    /// let manager = Manager::try_new(WindowBuilder::default())?
+    ///     .try_add_window("Inspector", WindowBuilder::default().with_title("Inspector"))?;
+    /// ```
+    pub fn try_add_window(
+        mut self,
+        class: &str,
+        builder: WindowBuilder,
+    ) -> Result<Self, WindowError> {
+        self.insert(class, builder)?;
+        return Ok(self);
     }
 
-    fn insert(&mut self, class: &str, builder: WindowBuilder) {
+    fn insert(&mut self, class: &str, builder: WindowBuilder) -> Result<(), WindowError> {
         let msger = self.msger.clone();
+        let id = WindowId::next();
+        let state = Arc::new(Mutex::new(WindowState {
+            want_all_keys: builder.want_all_keys,
+            no_erase_background: builder.no_erase_background,
+            constraints: SizeConstraints {
+                min: builder.min_size.or(self.min_size),
+                max: builder.max_size,
+                ..SizeConstraints::default()
+            },
+            ..WindowState::default()
+        }));
+        let window_state = state.clone();
         let class = class.to_string();
-        let mut added = false;
-        let mut hwnd: *mut HWND__ = std::ptr::null_mut();
-
-        let p_added = &mut added as *mut bool as usize;
-        let p_hwnd = &mut hwnd as *mut HWND as usize;
-
-        std::thread::spawn(move || unsafe {
-            let window = Window::register(&class, builder, &msger as *const Messenger, Self::setup);
+        let class_name = class.clone();
+        let (hwnd_sender, hwnd_receiver) = std::sync::mpsc::channel::<Result<usize, _>>();
+
+        let name = format!("dgews-window-{class}");
+
+        let spawned = std::thread::Builder::new().name(name).spawn(move || unsafe {
+            let data = WindowData::new(id, msger, window_state);
+            let window =
+                match Window::register(&class, builder, &data as *const WindowData, Self::setup) {
+                    Ok(window) => window,
+                    Err(error) => {
+                        // The Manager may have given up waiting already
+                        let _ = hwnd_sender.send(Err(error));
+                        return;
+                    }
+                };
 
-            let hwnd = (p_hwnd as *mut HWND).as_mut().unwrap();
-            *hwnd = window;
+            // The Manager may have given up waiting already
+            let _ = hwnd_sender.send(Ok(window as usize));
 
-            let not_added = (p_added as *mut bool).as_mut().unwrap();
-            *not_added = true;
+            OleInitialize(std::ptr::null_mut());
+            let drop_target = FileDropHandler::create(id, data.msger.clone(), data.state.clone());
+            RegisterDragDrop(window, drop_target);
 
+            // The messages of the hidden window of OLE come to this thread too, a drop from another process needs them
+            // The loop sleeps until a message arrives and ends with WM_QUIT which WM_DESTROY posts
             let mut msg = std::mem::zeroed();
-            loop {
-                if PeekMessageW(&mut msg, window, 0, 0, PM_REMOVE) > 0 {
-                    TranslateMessage(&msg);
-                    DispatchMessageW(&msg);
+            while GetMessageW(&mut msg, std::ptr::null_mut(), 0, 0) > 0 {
+                let accelerators = data.state.lock().unwrap().accelerators as HACCEL;
+                if !accelerators.is_null() && TranslateAcceleratorW(window, accelerators, &mut msg) != 0 {
+                    continue;
                 }
+
+                TranslateMessage(&msg);
+                DispatchMessageW(&msg);
             }
-        });
 
-        'window: loop {
-            if added {
-                let window = Window::from(hwnd as *mut _);
-                self.windows.insert(window.get_class_name(), window);
+            (*drop_target).Release();
+            OleUninitialize();
+
+            Window::unregister(&class);
+        });
+        let thread = match spawned {
+            Ok(thread) => thread,
+            Err(_) => return Err(WindowError::Creation { class: class_name, code: 0 }),
+        };
 
-                break 'window;
+        // Only the handle crosses the threads here. Input states are updated on the main thread in run()
+        let hwnd = match hwnd_receiver.recv_timeout(Self::CREATION_TIMEOUT) {
+            Ok(hwnd) => hwnd?,
+            Err(RecvTimeoutError::Timeout) => {
+                return Err(WindowError::Timeout {
+                    class: class_name,
+                    timeout: Self::CREATION_TIMEOUT,
+                });
+            }
+            // The thread has panicked before it could report back
+            Err(RecvTimeoutError::Disconnected) => {
+                return Err(WindowError::Creation { class: class_name, code: 0 });
             }
+        };
+        let mut window = Window::from(hwnd as HWND);
+        window.id = id;
+        window.state = state;
+        if let Some(cursor) = self.default_cursor {
+            window.set_default_cursor(cursor);
         }
+        window.state.lock().unwrap().raw_messages = self.raw_messages;
+        self.windows.insert(window.get_class_name(), window);
+        self.threads.insert(id, thread);
+        return Ok(());
     }
 
     /// Returns a reference to the default window of the manager
@@ -157,6 +357,20 @@ impl Manager {
         return self.get_window(Self::DGEWindowClassExWName);
     }
 
+    /// Asks the default window to repaint, so WindowEvents::RedrawRequested is sent for its whole client area (see Window::request_redraw()). The requests which arrive before the window is repainted are merged into one event
+    ///
+    /// # Example
+    ///
+    /// ```ignore
+    /// scene.add(model);
+    /// manager.request_redraw();
+    /// ```
+    pub fn request_redraw(&self) {
+        if let Some(window) = self.window() {
+            window.request_redraw();
+        }
+    }
+
     /// Returns a reference to the mut default window of the manager
     ///
     /// # Example
@@ -172,6 +386,164 @@ impl Manager {
         return self.get_mut_window(Self::DGEWindowClassExWName);
     }
 
+    /// Returns a reference to the window with the given id or None if there is no such window (e.g. it has already been closed)
+    ///
+    /// # Example
+    ///
+    /// ```ignore
+    /// manager.run(|events, control_flow, manager| {
+    ///     match events {
+    ///         Events::WindowEvents { id, event: WindowEvents::Moved { x, y } } => {
+    ///             if let Some(window) = manager.get_window_by_id(id) {
+    ///                 println!("{} has moved to ({x}, {y})", window.get_title());
+    ///             }
+    ///         }
+    ///         _=> {}
+    ///     }
+    /// });
+    /// ```
+    pub fn get_window_by_id(&self, id: WindowId) -> Option<&Window> {
+        return self.windows.values().find(|window| window.get_id() == id);
+    }
+
+    /// Returns a mutable reference to the window with the given id or None if there is no such window (e.g. it has just been closed)
+    ///
+    /// # Example
+    ///
+    /// ```ignore
+    /// manager.run(|events, control_flow, manager| {
+    ///     match events {
+    ///         Events::WindowEvents { id, event: WindowEvents::Moved { x, y } } => {
+    ///             if let Some(window) = manager.get_mut_window_by_id(id) {
+    ///                 window.set_title(&format!("({x}, {y})"));
+    ///             }
+    ///         }
+    ///         _=> {}
+    ///     }
+    /// });
+    /// ```
+    pub fn get_mut_window_by_id(&mut self, id: WindowId) -> Option<&mut Window> {
+        return self.windows.values_mut().find(|window| window.get_id() == id);
+    }
+
+    /// Returns the id of the default window of the manager (the one which has been given to Manager::new()) or None if it has been closed
+    ///
+    /// # Example
+    ///
+    /// ```ignore
+    /// manager.run(|events, control_flow, manager| {
+    ///     match events {
+    ///         Events::WindowEvents { id, event: WindowEvents::Close } => {
+    ///             if Some(id) == manager.primary_window_id() {
+    ///                 *control_flow = ControlFlow::Exit;
+    ///             }
+    ///         }
+    ///         _=> {}
+    ///     }
+    /// });
+    /// ```
+    pub fn primary_window_id(&self) -> Option<WindowId> {
+        return self.window().map(|window| window.get_id());
+    }
+
+    /// Returns how many windows are open
+    ///
+    /// # Example
+    ///
+    /// ```ignore
+    /// println!("{} windows are open", manager.window_count());
+    /// ```
+    pub fn window_count(&self) -> usize {
+        return self.windows.len();
+    }
+
+    /// Returns an iterator over every open window. The order is not specified
+    ///
+    /// # Example
+    ///
+    /// ```ignore
+    /// for window in manager.windows() {
+    ///     println!("{}: {}", window.get_id(), window.get_title());
+    /// }
+    /// ```
+    pub fn windows(&self) -> impl Iterator<Item = &Window> {
+        return self.windows.values();
+    }
+
+    /// Returns an iterator over every open window which can change them. The order is not specified
+    ///
+    /// # Example
+    ///
+    /// ```ignore
+    /// for window in manager.windows_mut() {
+    ///     window.set_title("Paused");
+    /// }
+    /// ```
+    pub fn windows_mut(&mut self) -> impl Iterator<Item = &mut Window> {
+        return self.windows.values_mut();
+    }
+
+    /// Returns the ids of every open window. The ids are the ones the events carry
+    ///
+    /// # Example
+    ///
+    /// ```ignore
+    /// for id in manager.window_ids() {
+    ///     println!("Window {id} is open");
+    /// }
+    /// ```
+    pub fn window_ids(&self) -> Vec<WindowId> {
+        return self.windows.values().map(|window| window.get_id()).collect();
+    }
+
+    /// Calls the closure with every open window, e.g. to change all of them at once
+    ///
+    /// # Example
+    ///
+    /// ```ignore
+    /// manager.for_each_window(|window| {
+    ///     let title = format!("{} (paused)", window.get_title());
+    ///     window.set_title(&title);
+    /// });
+    /// ```
+    pub fn for_each_window<F>(&mut self, mut func: F)
+    where
+        F: FnMut(&mut Window),
+    {
+        for window in self.windows.values_mut() {
+            func(window);
+        }
+    }
+
+    /// Returns whether any window of the manager has the keyboard focus, e.g. to pause the game while the user is in another program
+    ///
+    /// # Example
+    ///
+    /// ```ignore
+    /// manager.run(|events, control_flow, manager| {
+    ///     if !manager.is_focused() {
+    ///         return;
+    ///     }
+    ///     game.update();
+    /// });
+    /// ```
+    pub fn is_focused(&self) -> bool {
+        return self.input.focused.is_some();
+    }
+
+    /// Returns the id of the window which has the keyboard focus or None if no window of the manager has it
+    ///
+    /// # Example
+    ///
+    /// ```ignore
+    /// if manager.focused_window_id() == manager.primary_window_id() {
+    ///     println!("The main window is focused");
+    /// }
+    /// ```
+    pub fn focused_window_id(&self) -> Option<WindowId> {
+        return self.input.focused;
+    }
+
     /// Returns a reference to a window with a specified class. Panics if there is no a window with that class!
     ///
     /// # Example
@@ -199,6 +571,34 @@ impl Manager {
         return self.windows.get_mut(class);
     }
 
+    /// Destroys the window with the given id and returns once it is gone for good: the window has been removed from the Manager and its class has been unregistered, so it can be used again right away. WindowEvents::Close and WindowEvents::Destroyed are still sent for the window. Returns false if there is no such window
+    ///
+    /// # Example
+    ///
+    /// ```ignore
+    /// let id = manager.get_window("Splash").unwrap().get_id();
+    /// manager.destroy_window(id);
+    ///
+    /// let manager = manager.try_add_window("Splash", WindowBuilder::default())?;
+    /// ```
+    pub fn destroy_window(&mut self, id: WindowId) -> bool {
+        let class = match self.windows.iter().find(|(_, window)| window.get_id() == id) {
+            Some((class, _)) => class.clone(),
+            None => return false,
+        };
+
+        if let Some(mut window) = self.windows.remove(&class) {
+            window.destroy();
+        }
+
+        // The thread of the window unregisters the class after its message loop has ended
+        if let Some(thread) = self.threads.remove(&id) {
+            let _ = thread.join();
+        }
+
+        return true;
+    }
+
     /// Runs the program. This function takes a closure as its parameter which then gives back events, control flow and manager itself. The windows are processed in their own threads outside the main thread so that your program will not wait until the events are finished.
     ///
     /// # Example
@@ -221,361 +621,355 @@ impl Manager {
         T: FnMut(Events, &mut ControlFlow, &mut Manager),
     {
         let mut control_flow = ControlFlow::default();
+        let mut next_frame = std::time::Instant::now();
 
         'user_events_loop: loop {
-            while let Ok(main_events) = self.msger.try_recv() {
-                self.keyboard.clear();
-                self.mouse.clear_keystates();
-
-                let events = match main_events {
-                    MainEvents::MainWindowEvent { id, event } => match event {
-                        MainWindowEvents::Create => Events::WindowEvents {
-                            id,
-                            event: WindowEvents::Create,
-                        },
-                        MainWindowEvents::Close => {
-                            let class = Window::get_hwnd_class_name(id as *mut _);
-                            self.windows.remove(&class);
-
-                            Events::WindowEvents {
-                                id,
-                                event: WindowEvents::Close,
-                            }
-                        }
-                        MainWindowEvents::Maximized { width, height } => Events::WindowEvents {
-                            id,
-                            event: WindowEvents::Maximized { width, height },
-                        },
-                        MainWindowEvents::Minimized { width, height } => Events::WindowEvents {
-                            id,
-                            event: WindowEvents::Minimized { width, height },
-                        },
-                        MainWindowEvents::FramebufferChanged { width, height } => {
-                            Events::WindowEvents {
-                                id,
-                                event: WindowEvents::FramebufferChanged { width, height },
-                            }
-                        }
-                        MainWindowEvents::Moved { x, y } => Events::WindowEvents {
-                            id,
-                            event: WindowEvents::Moved { x, y },
-                        },
-                        MainWindowEvents::SetFocus => Events::WindowEvents {
-                            id,
-                            event: WindowEvents::SetFocus,
-                        },
-                        MainWindowEvents::LostFocus => Events::WindowEvents {
-                            id,
-                            event: WindowEvents::LostFocus,
-                        },
-                        MainWindowEvents::RedrawRequested => Events::WindowEvents {
-                            id,
-                            event: WindowEvents::RedrawRequested,
-                        }
-                    },
-                    MainEvents::MainKeyboardEvent { id, event } => match event {
-                        MainKeyboardEvents::Key {
-                            up,
-                            is_changed,
-                            keycode,
-                        } => {
-                            if keycode == Key::ALT {
-                                println!("Alt up: {}, changed: {}, keycode: {}, is_down: {}, is_changed: {}, is_released: {}", up, is_changed, keycode, self.keyboard.is_down(keycode), self.keyboard.is_changed(keycode), self.keyboard.is_released(keycode));
-
-                                Events::KeyboardEvents {
-                                    id,
-                                    event: KeyboardEvents::Key {
-                                        keycode,
-                                        action: Action::Release,
-                                    },
-                                }
-                            } else
-                            if up {
-                                self.keyboard.set_is_down(keycode, false);
-                                self.keyboard.set_is_changed(keycode, true);
-                                self.keyboard.set_is_released(keycode, true);
-
-                                Events::KeyboardEvents {
-                                    id,
-                                    event: KeyboardEvents::Key {
-                                        keycode,
-                                        action: Action::Release,
-                                    },
-                                }
-                            } else {
-                                self.keyboard.set_is_down(keycode, true);
-                                self.keyboard.set_is_changed(keycode, is_changed);
-
-                                if !self.keyboard.is_changed(keycode) {
-                                    Events::KeyboardEvents {
-                                        id,
-                                        event: KeyboardEvents::Key {
-                                            keycode,
-                                            action: Action::Down,
-                                        },
-                                    }
-                                } else if self.keyboard.is_released(keycode) {
-                                    self.keyboard.set_is_released(keycode, false);
-                                    Events::KeyboardEvents {
-                                        id,
-                                        event: KeyboardEvents::Key {
-                                            keycode,
-                                            action: Action::Press,
-                                        },
-                                    }
-                                } else {
-                                    Events::None
-                                }
-                            }
-                        }
-
-                        MainKeyboardEvents::Char { keycode } => {
-                            self.keyboard.set_is_char(keycode, true);
-                            Events::KeyboardEvents {
-                                id,
-                                event: KeyboardEvents::Char { keycode },
-                            }
-                        }
-                    },
-                    MainEvents::MainMouseEvent { id, event } => match event {
-                        MainMouseEvents::Scroll { y_offset } => Events::MouseEvents {
-                            id,
-                            event: MouseEvents::Scroll { y_offset },
-                        },
-                        MainMouseEvents::LButton { up, pos } => {
-                            if up {
-                                self.mouse.set_l_button_down(false);
-                                self.mouse.set_l_button_released(true);
-                                self.mouse.set_l_button_changed(true);
-
-                                Events::MouseEvents {
-                                    id,
-                                    event: MouseEvents::LButton {
-                                        action: Action::Release,
-                                        pos,
-                                    },
-                                }
-                            } else {
-                                self.mouse.set_l_button_down(true);
-                                self.mouse.set_l_button_released(false);
-                                self.mouse.set_l_button_changed(true);
-
-                                Events::MouseEvents {
-                                    id,
-                                    event: MouseEvents::LButton {
-                                        action: Action::Press,
-                                        pos,
-                                    },
-                                }
-                            }
-                        }
-                        MainMouseEvents::RButton { up, pos } => {
-                            if up {
-                                self.mouse.set_r_button_down(false);
-                                self.mouse.set_r_button_released(true);
-                                self.mouse.set_r_button_changed(true);
-
-                                Events::MouseEvents {
-                                    id,
-                                    event: MouseEvents::LButton {
-                                        action: Action::Release,
-                                        pos,
-                                    },
-                                }
-                            } else {
-                                self.mouse.set_r_button_down(true);
-                                self.mouse.set_r_button_released(false);
-                                self.mouse.set_r_button_changed(true);
-
-                                Events::MouseEvents {
-                                    id,
-                                    event: MouseEvents::LButton {
-                                        action: Action::Press,
-                                        pos,
-                                    },
-                                }
-                            }
-                        }
-                        MainMouseEvents::MButton { up, pos } => {
-                            if up {
-                                self.mouse.set_m_button_down(false);
-                                self.mouse.set_m_button_released(true);
-                                self.mouse.set_m_button_changed(true);
-
-                                Events::MouseEvents {
-                                    id,
-                                    event: MouseEvents::LButton {
-                                        action: Action::Release,
-                                        pos,
-                                    },
-                                }
-                            } else {
-                                self.mouse.set_m_button_down(true);
-                                self.mouse.set_m_button_released(false);
-                                self.mouse.set_m_button_changed(true);
-
-                                Events::MouseEvents {
-                                    id,
-                                    event: MouseEvents::LButton {
-                                        action: Action::Press,
-                                        pos,
-                                    },
-                                }
-                            }
-                        }
-                        MainMouseEvents::XButton { up, wparam, pos } => {
-                            if up {
-                                if HIWORD(wparam) & XBUTTON1 > 0 {
-                                    self.mouse.set_x1_button_down(false);
-                                    self.mouse.set_x1_button_released(true);
-                                    self.mouse.set_x1_button_changed(true);
-
-                                    Events::MouseEvents {
-                                        id,
-                                        event: MouseEvents::X1Button {
-                                            action: Action::Release,
-                                            pos,
-                                        },
-                                    }
-                                } else if HIWORD(wparam) & XBUTTON2 > 0 {
-                                    self.mouse.set_x2_button_down(false);
-                                    self.mouse.set_x2_button_released(true);
-                                    self.mouse.set_x2_button_changed(true);
-
-                                    Events::MouseEvents {
-                                        id,
-                                        event: MouseEvents::X2Button {
-                                            action: Action::Release,
-                                            pos,
-                                        },
-                                    }
-                                } else {
-                                    Events::None
-                                }
-                            } else {
-                                if LOWORD(wparam as u32) as usize & MK_XBUTTON1 > 0 {
-                                    self.mouse.set_x1_button_down(true);
-                                    self.mouse.set_x1_button_released(false);
-                                    self.mouse.set_x1_button_changed(true);
-
-                                    Events::MouseEvents {
-                                        id,
-                                        event: MouseEvents::X1Button {
-                                            action: Action::Press,
-                                            pos,
-                                        },
-                                    }
-                                } else if LOWORD(wparam as u32) as usize & MK_XBUTTON2 > 0 {
-                                    self.mouse.set_x2_button_down(true);
-                                    self.mouse.set_x2_button_released(false);
-                                    self.mouse.set_x2_button_changed(true);
-
-                                    Events::MouseEvents {
-                                        id,
-                                        event: MouseEvents::X2Button {
-                                            action: Action::Press,
-                                            pos,
-                                        },
-                                    }
-                                } else {
-                                    Events::None
-                                }
-                            }
-                        }
-                        MainMouseEvents::MouseMove { x, y } => {
-                            self.mouse.update_pos(x, y);
-                            Events::MouseEvents {
-                                id,
-                                event: MouseEvents::MouseMove {
-                                    x: self.mouse.x(),
-                                    y: self.mouse.y(),
-                                    last_x: self.mouse.last_x(),
-                                    last_y: self.mouse.last_y(),
-                                    dx: self.mouse.x_offset(),
-                                    dy: self.mouse.y_offset(),
-                                },
-                            }
-                        }
-                    },
-                };
+            // One frame per pass, so time() and frame_stats() agree however often they are called
+            self.timer.update();
 
-                func(events, &mut control_flow, self);
+            while let Ok(main_events) = self.msger.try_recv() {
+                let events = self.process(main_events);
+                if self.dispatch(&mut func, events, &mut control_flow) {
+                    break 'user_events_loop;
+                }
+            }
 
-                match control_flow {
-                    ControlFlow::Continue => {}
-                    ControlFlow::Exit => {
-                        self.close = true;
+            if let ControlFlow::WaitUntil(deadline) = control_flow {
+                // An event keeps the deadline, so the closure can still change it while handling the event
+                if !self.msger.wait_until(deadline) {
+                    control_flow = ControlFlow::Continue;
+                    if self.dispatch(&mut func, Events::None, &mut control_flow) {
                         break 'user_events_loop;
                     }
-                    ControlFlow::ExitWithCode(exit_code) => {
-                        panic!("Exit code with {}", exit_code);
-                    }
                 }
+
+                continue 'user_events_loop;
             }
 
-            control_flow = ControlFlow::Continue;
-        }
-    }
+            if control_flow == ControlFlow::Wait {
+                let woken = match self.wait_timeout {
+                    Some(wait_timeout) => {
+                        self.msger.wait_until(std::time::Instant::now() + wait_timeout)
+                    }
+                    None => {
+                        self.msger.wait();
+                        true
+                    }
+                };
 
-    unsafe fn wndproc(
-        msger: &Messenger,
-        hwnd: HWND,
-        msg: UINT,
-        wparam: WPARAM,
-        lparam: LPARAM,
-    ) -> LRESULT {
-        match msg {
-            WM_DESTROY => {
-                // println!("{}", Window::get_hwnd_class_name(hwnd));
+                if !woken && self.dispatch(&mut func, Events::AboutToWait, &mut control_flow) {
+                    break 'user_events_loop;
+                }
 
-                msger.send(MainEvents::MainWindowEvent {
-                    id: hwnd as usize,
-                    event: MainWindowEvents::Close,
-                });
-                PostQuitMessage(0);
+                continue 'user_events_loop;
             }
 
-            WM_MOUSEMOVE => {
-                let x = MAKEPOINTS(lparam as u32).x;
-                let y = MAKEPOINTS(lparam as u32).y;
-
-                msger.send(MainEvents::MainMouseEvent {
-                    id: hwnd as usize,
-                    event: MainMouseEvents::MouseMove { x, y },
-                });
+            if control_flow == ControlFlow::Poll {
+                if self.dispatch(&mut func, Events::None, &mut control_flow) {
+                    break 'user_events_loop;
+                }
+            } else {
+                control_flow = ControlFlow::Continue;
             }
 
-            WM_MOUSEWHEEL => {
-                let delta = GET_WHEEL_DELTA_WPARAM(wparam);
-                msger.send(MainEvents::MainMouseEvent {
-                    id: hwnd as usize,
-                    event: MainMouseEvents::Scroll {
-                        y_offset: delta / WHEEL_DELTA,
-                    },
-                });
-            }
+            if let Some(frame_rate_limit) = self.frame_rate_limit {
+                next_frame += std::time::Duration::from_secs_f32(1.0f32 / frame_rate_limit);
 
-            WM_CHAR => {
-                msger.send(MainEvents::MainKeyboardEvent {
-                    id: hwnd as usize,
-                    event: MainKeyboardEvents::Char { keycode: wparam },
-                });
+                let now = std::time::Instant::now();
+                if next_frame > now {
+                    std::thread::sleep(next_frame - now);
+                } else {
+                    // Too slow to hold the rate, so the lost time is not caught up with a burst of frames
+                    next_frame = now;
+                }
             }
+        }
+    }
 
-            WM_KEYDOWN | WM_SYSKEYDOWN => {
-                msger.send(MainEvents::MainKeyboardEvent {
-                    id: hwnd as usize,
-                    event: MainKeyboardEvents::Key {
-                        up: false,
-                        keycode: wparam,
-                        is_changed: (lparam & (1 << 30)) == 0,
-                    },
-                });
+    /// Runs the program like run() and returns a value to the caller, e.g. the choice of the user in a dialog-style window. The closure returns Some(value) to keep it (usually together with ControlFlow::Exit) and the last kept value is returned. None is returned if the loop ends without one (e.g. the last window has been closed)
+    ///
+    /// # Example
+    ///
+    /// ```ignore
+    /// let mut dialog = Manager::new(WindowBuilder::default().with_title("Save changes?"));
+    ///
+    /// let save = dialog.run_app(|events, control_flow, manager| {
+    ///     match events {
+    ///         Events::KeyboardEvents { id: _, event: KeyboardEvents::Key { keycode: Key::Y, action: Action::Press } } => {
+    ///             *control_flow = ControlFlow::Exit;
+    ///             return Some(true);
+    ///         }
+    ///         Events::KeyboardEvents { id: _, event: KeyboardEvents::Key { keycode: Key::N, action: Action::Press } } => {
+    ///             *control_flow = ControlFlow::Exit;
+    ///             return Some(false);
+    ///         }
+    ///         _=> return None,
+    ///     }
+    /// });
+    /// ```
+    pub fn run_app<R, T>(&mut self, mut func: T) -> Option<R>
+    where
+        T: FnMut(Events, &mut ControlFlow, &mut Manager) -> Option<R>,
+    {
+        let mut result = None;
+
+        self.run(|events, control_flow, manager| {
+            if let Some(value) = func(events, control_flow, manager) {
+                result = Some(value);
+            }
+        });
+
+        return result;
+    }
+
+    // Returns true if the loop has to exit
+    fn dispatch<T>(&mut self, func: &mut T, events: Events, control_flow: &mut ControlFlow) -> bool
+    where
+        T: FnMut(Events, &mut ControlFlow, &mut Manager),
+    {
+        let result = std::panic::catch_unwind(std::panic::AssertUnwindSafe(|| {
+            func(events, control_flow, self);
+        }));
+
+        if let Err(panic) = result {
+            self.shutdown();
+            std::panic::resume_unwind(panic);
+        }
+
+        // No event would ever wake the waiting modes up again
+        if self.exit_on_last_window
+            && self.all_closed()
+            && matches!(
+                *control_flow,
+                ControlFlow::Continue
+                    | ControlFlow::Poll
+                    | ControlFlow::Wait
+                    | ControlFlow::WaitUntil(_)
+            )
+        {
+            *control_flow = ControlFlow::Exit;
+        }
+
+        match *control_flow {
+            ControlFlow::Continue
+            | ControlFlow::Poll
+            | ControlFlow::Wait
+            | ControlFlow::WaitUntil(_) => {}
+            ControlFlow::Exit => {
+                self.close = true;
+                self.exit_code = Some(0);
+                return true;
+            }
+            ControlFlow::ExitWithCode(exit_code) => {
+                self.close = true;
+                self.exit_code = Some(exit_code);
+                return true;
+            }
+        }
+
+        return false;
+    }
+
+    /// Returns an iterator over the events which have already arrived. It does not block and ends once they are drained, so it fits into a loop of your own instead of the run() closure. The events are translated the same way as in run()
+    ///
+    /// # Example
+    ///
+    /// ```ignore
+    /// while !manager.all_closed() {
+    ///     for event in manager.events() {
+    ///         match event {
+    ///             Events::WindowEvents { id, event: WindowEvents::Close } => println!("Window {id} has been closed"),
+    ///             _=> {}
+    ///         }
+    ///     }
+    ///
+    ///     render();
+    /// }
+    /// ```
+    pub fn events(&mut self) -> EventIter<'_> {
+        return EventIter { manager: self };
+    }
+
+    /// Blocks until the next event arrives and returns it. It is the simplest way to handle the events in a tool that does not need a render loop. Once all the windows are closed (the last WindowEvents::Destroyed has been returned) it does not block anymore and returns Events::None
+    ///
+    /// # Example
+    ///
+    /// ```ignore
+    /// loop {
+    ///     match manager.wait_event() {
+    ///         Events::WindowEvents { id: _, event: WindowEvents::RedrawRequested { .. } } => draw(),
+    ///         Events::None => break,
+    ///         _=> {}
+    ///     }
+    /// }
+    /// ```
+    pub fn wait_event(&mut self) -> Events {
+        while !self.all_closed() {
+            let main_events = match self.msger.recv() {
+                Ok(main_events) => main_events,
+                Err(_) => break,
+            };
+
+            let events = self.process(main_events);
+            if events != Events::None {
+                return events;
+            }
+        }
+
+        return Events::None;
+    }
+
+    fn process(&mut self, main_events: MainEvents) -> Events {
+        if let MainEvents::MainWindowEvent {
+            id,
+            event: MainWindowEvents::Destroyed,
+        } = main_events
+        {
+            self.windows.retain(|_, window| window.get_id() != id);
+            // The thread ends by itself after the window, so it is not waited for
+            self.threads.remove(&id);
+        }
+
+        let events = translate(&mut self.input, main_events);
+        if events != Events::None {
+            if let Some(EventTap(event_tap)) = self.event_tap.as_mut() {
+                event_tap(&events);
+            }
+        }
+
+        return events;
+    }
+
+    fn shutdown(&mut self) {
+        for window in self.windows.values_mut() {
+            window.destroy();
+        }
+
+        self.windows.clear();
+        self.close = true;
+    }
+
+    unsafe fn wndproc(
+        data: &WindowData,
+        hwnd: HWND,
+        msg: UINT,
+        wparam: WPARAM,
+        lparam: LPARAM,
+    ) -> LRESULT {
+        match msg {
+            WM_DESTROY => {
+                // println!("{}", Window::get_hwnd_class_name(hwnd));
+                RevokeDragDrop(hwnd);
+
+                data.msger.send(MainEvents::MainWindowEvent {
+                    id: data.id,
+                    event: MainWindowEvents::Close,
+                });
+                PostQuitMessage(0);
+            }
+
+            WM_CLOSE => {
+                data.msger.send(MainEvents::MainWindowEvent {
+                    id: data.id,
+                    event: MainWindowEvents::CloseRequested,
+                });
+
+                // The program destroys the window itself once it has decided
+                if data.state.lock().unwrap().prevent_close {
+                    return 0;
+                }
+            }
+
+            WM_ERASEBKGND => {
+                // Claims the background as erased so that only WM_PAINT draws the pixels
+                if data.state.lock().unwrap().no_erase_background {
+                    return TRUE as LRESULT;
+                }
+            }
+
+            WM_NCDESTROY => {
+                data.msger.send(MainEvents::MainWindowEvent {
+                    id: data.id,
+                    event: MainWindowEvents::Destroyed,
+                });
+            }
+
+            WM_MOUSEMOVE => {
+                let x = MAKEPOINTS(lparam as u32).x;
+                let y = MAKEPOINTS(lparam as u32).y;
+
+                // WM_MOUSELEAVE is sent only once per TrackMouseEvent() call
+                let tracking_leave =
+                    std::mem::replace(&mut data.state.lock().unwrap().tracking_leave, true);
+                if !tracking_leave {
+                    let mut track = TRACKMOUSEEVENT {
+                        cbSize: std::mem::size_of::<TRACKMOUSEEVENT>() as u32,
+                        dwFlags: TME_LEAVE,
+                        hwndTrack: hwnd,
+                        dwHoverTime: 0,
+                    };
+                    TrackMouseEvent(&mut track);
+                }
+
+                data.msger.send(MainEvents::MainMouseEvent {
+                    id: data.id,
+                    event: MainMouseEvents::MouseMove { x, y },
+                });
+            }
+
+            WM_MOUSELEAVE => {
+                data.state.lock().unwrap().tracking_leave = false;
+
+                // Without the capture the button up message goes to whichever window is under the cursor
+                if GetCapture() != hwnd {
+                    Self::release_held_buttons(data, hwnd);
+                }
+
+                data.msger.send(MainEvents::MainMouseEvent {
+                    id: data.id,
+                    event: MainMouseEvents::Leave,
+                });
+            }
+
+            WM_CAPTURECHANGED => {
+                if lparam as HWND != hwnd {
+                    Self::release_held_buttons(data, hwnd);
+                }
+            }
+
+            WM_MOUSEWHEEL => {
+                let delta = GET_WHEEL_DELTA_WPARAM(wparam);
+                data.msger.send(MainEvents::MainMouseEvent {
+                    id: data.id,
+                    event: MainMouseEvents::Scroll {
+                        y_offset: delta / WHEEL_DELTA,
+                        modifiers: Modifiers::from_wparam(wparam),
+                    },
+                });
+            }
+
+            WM_CHAR => {
+                data.msger.send(MainEvents::MainKeyboardEvent {
+                    id: data.id,
+                    event: MainKeyboardEvents::Char { keycode: wparam },
+                });
+            }
+
+            WM_KEYDOWN | WM_SYSKEYDOWN => {
+                data.msger.send(MainEvents::MainKeyboardEvent {
+                    id: data.id,
+                    event: MainKeyboardEvents::Key {
+                        up: false,
+                        keycode: wparam,
+                        is_changed: (lparam & (1 << 30)) == 0,
+                    },
+                });
+                Self::send_toggles(data, &[wparam]);
             }
 
             WM_KEYUP | WM_SYSKEYUP => {
-                msger.send(MainEvents::MainKeyboardEvent {
-                    id: hwnd as usize,
+                data.msger.send(MainEvents::MainKeyboardEvent {
+                    id: data.id,
                     event: MainKeyboardEvents::Key {
                         up: true,
                         keycode: wparam,
@@ -585,21 +979,25 @@ impl Manager {
             }
 
             WM_SIZE => {
-                let width = LOWORD(lparam as u32) as i32;
-                let height = HIWORD(lparam as u32) as i32;
+                let size = Size::new(LOWORD(lparam as u32) as i32, HIWORD(lparam as u32) as i32);
+                let is_empty = size.width == 0 || size.height == 0;
+
+                // Zero sized framebuffers crash the surfaces of the gpu backends
+                let Size { width, height } = size.clamp(Size::new(1, 1), Size::new(i32::MAX, i32::MAX));
+
                 if wparam == SIZE_MAXIMIZED {
-                    msger.send(MainEvents::MainWindowEvent {
-                        id: hwnd as usize,
+                    data.msger.send(MainEvents::MainWindowEvent {
+                        id: data.id,
                         event: MainWindowEvents::Maximized { width, height },
                     });
-                } else if wparam == SIZE_MINIMIZED {
-                    msger.send(MainEvents::MainWindowEvent {
-                        id: hwnd as usize,
+                } else if wparam == SIZE_MINIMIZED || is_empty {
+                    data.msger.send(MainEvents::MainWindowEvent {
+                        id: data.id,
                         event: MainWindowEvents::Minimized { width, height },
                     });
                 } else {
-                    msger.send(MainEvents::MainWindowEvent {
-                        id: hwnd as usize,
+                    data.msger.send(MainEvents::MainWindowEvent {
+                        id: data.id,
                         event: MainWindowEvents::FramebufferChanged { width, height },
                     });
                 }
@@ -608,21 +1006,33 @@ impl Manager {
             WM_MOVE => {
                 let x = LOWORD(lparam as u32) as i32;
                 let y = HIWORD(lparam as u32) as i32;
-                msger.send(MainEvents::MainWindowEvent {
-                    id: hwnd as usize,
+                data.msger.send(MainEvents::MainWindowEvent {
+                    id: data.id,
                     event: MainWindowEvents::Moved { x, y },
                 });
+
+                // The first move only records the monitor the window is created on
+                let monitor = MonitorId::from_window(hwnd);
+                let last_monitor =
+                    std::mem::replace(&mut data.state.lock().unwrap().monitor, monitor);
+                if last_monitor != monitor && last_monitor != MonitorId::default() {
+                    data.msger.send(MainEvents::MainWindowEvent {
+                        id: data.id,
+                        event: MainWindowEvents::MonitorChanged { monitor },
+                    });
+                }
             }
 
             WM_LBUTTONDOWN => {
                 let x = LOWORD(lparam as u32) as i32;
                 let y = HIWORD(lparam as u32) as i32;
 
-                msger.send(MainEvents::MainMouseEvent {
-                    id: hwnd as usize,
+                data.msger.send(MainEvents::MainMouseEvent {
+                    id: data.id,
                     event: MainMouseEvents::LButton {
                         up: false,
                         pos: Point::new(x, y),
+                        modifiers: Modifiers::from_wparam(wparam),
                     },
                 });
             }
@@ -631,11 +1041,12 @@ impl Manager {
                 let x = LOWORD(lparam as u32) as i32;
                 let y = HIWORD(lparam as u32) as i32;
 
-                msger.send(MainEvents::MainMouseEvent {
-                    id: hwnd as usize,
+                data.msger.send(MainEvents::MainMouseEvent {
+                    id: data.id,
                     event: MainMouseEvents::LButton {
                         up: true,
                         pos: Point::new(x, y),
+                        modifiers: Modifiers::from_wparam(wparam),
                     },
                 });
             }
@@ -644,11 +1055,12 @@ impl Manager {
                 let x = LOWORD(lparam as u32) as i32;
                 let y = HIWORD(lparam as u32) as i32;
 
-                msger.send(MainEvents::MainMouseEvent {
-                    id: hwnd as usize,
+                data.msger.send(MainEvents::MainMouseEvent {
+                    id: data.id,
                     event: MainMouseEvents::RButton {
                         up: false,
                         pos: Point::new(x, y),
+                        modifiers: Modifiers::from_wparam(wparam),
                     },
                 });
             }
@@ -657,11 +1069,12 @@ impl Manager {
                 let x = LOWORD(lparam as u32) as i32;
                 let y = HIWORD(lparam as u32) as i32;
 
-                msger.send(MainEvents::MainMouseEvent {
-                    id: hwnd as usize,
+                data.msger.send(MainEvents::MainMouseEvent {
+                    id: data.id,
                     event: MainMouseEvents::RButton {
                         up: true,
                         pos: Point::new(x, y),
+                        modifiers: Modifiers::from_wparam(wparam),
                     },
                 });
             }
@@ -670,11 +1083,12 @@ impl Manager {
                 let x = LOWORD(lparam as u32) as i32;
                 let y = HIWORD(lparam as u32) as i32;
 
-                msger.send(MainEvents::MainMouseEvent {
-                    id: hwnd as usize,
+                data.msger.send(MainEvents::MainMouseEvent {
+                    id: data.id,
                     event: MainMouseEvents::MButton {
                         up: false,
                         pos: Point::new(x, y),
+                        modifiers: Modifiers::from_wparam(wparam),
                     },
                 });
             }
@@ -683,11 +1097,12 @@ impl Manager {
                 let x = LOWORD(lparam as u32) as i32;
                 let y = HIWORD(lparam as u32) as i32;
 
-                msger.send(MainEvents::MainMouseEvent {
-                    id: hwnd as usize,
+                data.msger.send(MainEvents::MainMouseEvent {
+                    id: data.id,
                     event: MainMouseEvents::MButton {
                         up: true,
                         pos: Point::new(x, y),
+                        modifiers: Modifiers::from_wparam(wparam),
                     },
                 });
             }
@@ -696,12 +1111,13 @@ impl Manager {
                 let x = LOWORD(lparam as u32) as i32;
                 let y = HIWORD(lparam as u32) as i32;
 
-                msger.send(MainEvents::MainMouseEvent {
-                    id: hwnd as usize,
+                data.msger.send(MainEvents::MainMouseEvent {
+                    id: data.id,
                     event: MainMouseEvents::XButton {
                         up: false,
                         pos: Point::new(x, y),
                         wparam: wparam as u32,
+                        modifiers: Modifiers::from_wparam(wparam),
                     },
                 });
             }
@@ -710,203 +1126,1220 @@ impl Manager {
                 let x = LOWORD(lparam as u32) as i32;
                 let y = HIWORD(lparam as u32) as i32;
 
-                msger.send(MainEvents::MainMouseEvent {
-                    id: hwnd as usize,
+                data.msger.send(MainEvents::MainMouseEvent {
+                    id: data.id,
                     event: MainMouseEvents::XButton {
                         up: true,
                         pos: Point::new(x, y),
                         wparam: wparam as u32,
+                        modifiers: Modifiers::from_wparam(wparam),
                     },
                 });
             }
 
             WM_SETFOCUS => {
-                msger.send(MainEvents::MainWindowEvent {
-                    id: hwnd as usize,
+                data.msger.send(MainEvents::MainWindowEvent {
+                    id: data.id,
                     event: MainWindowEvents::SetFocus,
                 });
+                // The lock keys may have been toggled while another window had the focus
+                Self::send_toggles(data, &[Key::CAPITAL, Key::NUMLOCK, Key::SCROLL]);
             }
 
             WM_KILLFOCUS => {
-                msger.send(MainEvents::MainWindowEvent {
-                    id: hwnd as usize,
+                data.msger.send(MainEvents::MainWindowEvent {
+                    id: data.id,
                     event: MainWindowEvents::LostFocus,
                 });
             }
 
-            WM_PAINT => {
-                msger.send(MainEvents::MainWindowEvent {
-                    id: hwnd as usize,
-                    event: MainWindowEvents::RedrawRequested,
-                });
+            WM_GETMINMAXINFO => {
+                // The callback runs without the lock, it may call the methods of the Window or resize it again
+                let (constraints, callback) = {
+                    let mut state = data.state.lock().unwrap();
+                    (state.constraints, state.size_constraints.take())
+                };
+                if !constraints.is_empty() || callback.is_some() {
+                    let mmi = (lparam as *mut MINMAXINFO).as_mut().unwrap();
+                    let mut info = MinMaxInfo::from(*mmi);
+                    let frame = Self::frame_size(hwnd);
+
+                    // The callback of the window has the last word over the constraints
+                    if let Some(min) = constraints.min {
+                        info.min_track_size =
+                            Size::new(min.width + frame.width, min.height + frame.height);
+                    }
+                    if let Some(max) = constraints.max {
+                        info.max_track_size =
+                            Size::new(max.width + frame.width, max.height + frame.height);
+                    }
+                    if let Some(mut callback) = callback {
+                        callback(&mut info);
+
+                        // Unless the callback has replaced itself
+                        let mut state = data.state.lock().unwrap();
+                        if state.size_constraints.is_none() {
+                            state.size_constraints = Some(callback);
+                        }
+                    }
+                    info.apply(mmi);
+
+                    return 0;
+                }
             }
 
-            _ => {}
-        }
+            WM_ENTERSIZEMOVE => {
+                let mut state = data.state.lock().unwrap();
+                state.size_move = true;
+                state.resizing = false;
+            }
 
-        return DefWindowProcW(hwnd, msg, wparam, lparam);
-    }
+            WM_EXITSIZEMOVE => {
+                let resizing = {
+                    let mut state = data.state.lock().unwrap();
+                    state.size_move = false;
+                    std::mem::replace(&mut state.resizing, false)
+                };
 
-    unsafe extern "system" fn process_messages(
-        hwnd: HWND,
-        msg: UINT,
-        wparam: WPARAM,
-        lparam: LPARAM,
-    ) -> LRESULT {
-        let msger = (GetWindowLongPtrW(hwnd, GWLP_USERDATA) as *const Messenger)
-            .as_ref()
-            .unwrap();
+                // Dragging the title bar runs the same modal loop, but only a resize is reported
+                if resizing {
+                    let mut client: RECT = std::mem::zeroed();
+                    GetClientRect(hwnd, &mut client);
+                    let Size { width, height } =
+                        Size::new(client.right - client.left, client.bottom - client.top)
+                            .max(Size::new(1, 1));
+                    data.msger.send(MainEvents::MainWindowEvent {
+                        id: data.id,
+                        event: MainWindowEvents::ResizeEnded { width, height },
+                    });
+                }
+            }
 
-        return Self::wndproc(msger, hwnd, msg, wparam, lparam);
-    }
+            WM_SIZING => {
+                let started = {
+                    let mut state = data.state.lock().unwrap();
+                    let started = state.size_move && !state.resizing;
+                    state.resizing |= state.size_move;
+                    started
+                };
+                if started {
+                    data.msger.send(MainEvents::MainWindowEvent {
+                        id: data.id,
+                        event: MainWindowEvents::ResizeStarted,
+                    });
+                }
 
-    unsafe extern "system" fn setup(
-        hwnd: HWND,
-        msg: UINT,
-        wparam: WPARAM,
-        lparam: LPARAM,
-    ) -> LRESULT {
-        if msg == WM_CREATE {
-            let create_struct = lparam as *mut CREATESTRUCTW;
-            let msger_ptr = create_struct.as_ref().unwrap().lpCreateParams as *const Messenger;
-            let msger = msger_ptr.as_ref().unwrap();
-            SetWindowLongPtrW(hwnd, GWLP_USERDATA, msger_ptr as isize);
-            SetWindowLongPtrW(hwnd, GWLP_WNDPROC, Self::process_messages as isize);
-            msger.send(MainEvents::MainWindowEvent {
-                id: hwnd as usize,
-                event: MainWindowEvents::Create,
-            });
-            return Self::wndproc(msger, hwnd, msg, wparam, lparam);
-        }
+                let constraints = data.state.lock().unwrap().constraints;
+                if !constraints.is_empty() {
+                    let rect = (lparam as *mut RECT).as_mut().unwrap();
+                    let frame = Self::frame_size(hwnd);
+                    let edge = wparam as u32;
+
+                    let client = Size::new(
+                        rect.right - rect.left - frame.width,
+                        rect.bottom - rect.top - frame.height,
+                    );
+                    let keep_width = edge != WMSZ_TOP && edge != WMSZ_BOTTOM;
+                    let client = constraints.apply(client, keep_width);
+
+                    // The dragged edges move while the opposite ones stay in place
+                    if edge == WMSZ_LEFT || edge == WMSZ_TOPLEFT || edge == WMSZ_BOTTOMLEFT {
+                        rect.left = rect.right - client.width - frame.width;
+                    } else {
+                        rect.right = rect.left + client.width + frame.width;
+                    }
+                    if edge == WMSZ_TOP || edge == WMSZ_TOPLEFT || edge == WMSZ_TOPRIGHT {
+                        rect.top = rect.bottom - client.height - frame.height;
+                    } else {
+                        rect.bottom = rect.top + client.height + frame.height;
+                    }
 
-        return DefWindowProcW(hwnd, msg, wparam, lparam);
-    }
+                    return TRUE as LRESULT;
+                }
+            }
 
-    /// Retrieves typed Keyboard buttons and keys. (Case sensitie!)
-    ///
-    /// # Example
-    ///
-    /// ```ignore
-    /// if manager.get_char(u) {
-    ///     println!("small case u char is typed");
-    /// } else if manager.get_char(T) {
-    ///     println!("capital case T char is typed");
-    /// }
-    /// ```
-    pub fn get_char(&self, char: usize) -> bool {
-        return self.keyboard.is_char(char);
-    }
+            WM_NCCALCSIZE => {
+                if wparam == TRUE as WPARAM && data.state.lock().unwrap().titlebar_height.is_some() {
+                    let params = (lparam as *mut NCCALCSIZE_PARAMS).as_mut().unwrap();
+                    let (frame_x, frame_y) = Self::resize_border();
 
-    /// Retrieves the state of the Keyboard buttons and keys. (Not case sensitive!)
-    ///
-    /// # Example
-    ///
-    /// ```ignore
-    /// if manager.get_key(TAB) == Action::Down {
-    ///     println!("Tabbar is being pressed!");
-    /// }
-    /// ```
-    pub fn get_key(&self, keycode: usize) -> Action {
-        return if self.keyboard.is_down(keycode) && !self.keyboard.is_changed(keycode) {
-            Action::Down
-        } else if self.keyboard.is_down(keycode) && self.keyboard.is_changed(keycode) {
-            Action::Press
-        } else if !self.keyboard.is_down(keycode) && self.keyboard.is_changed(keycode) {
-            Action::Release
-        } else {
-            Action::None
-        };
-    }
+                    // Only the title bar and the top border are removed, the other borders still resize the window
+                    params.rgrc[0].left += frame_x;
+                    params.rgrc[0].right -= frame_x;
+                    params.rgrc[0].bottom -= frame_y;
 
-    /// Retrieves the state of the mouse buttons
-    ///
-    /// # Example
-    ///
-    /// ```ignore
-    /// if manager.get_mouse_button(Button::MBUTTON) == Action::Press {
-    ///     *control_flow = ControlFlow::Exit;
-    /// }
-    /// ```
-    pub fn get_mouse_button(&self, button: usize) -> Action {
-        match button {
-            Button::LBUTTON => {
-                if self.mouse.l_button().is_changed() && self.mouse.l_button.is_down() {
-                    return Action::Press;
-                } else if !self.mouse.l_button().is_changed() && self.mouse.l_button.is_down() {
-                    return Action::Down;
-                } else if !self.mouse.l_button().is_down() && self.mouse.l_button().is_changed() {
-                    return Action::Release;
-                } else {
-                    return Action::None;
+                    // Maximized windows overhang the monitor by the size of the border
+                    if IsZoomed(hwnd) != 0 {
+                        params.rgrc[0].top += frame_y;
+                    }
+
+                    return 0;
                 }
             }
 
-            Button::RBUTTON => {
-                if self.mouse.r_button().is_changed() && self.mouse.r_button.is_down() {
-                    return Action::Press;
-                } else if !self.mouse.r_button().is_changed() && self.mouse.r_button.is_down() {
-                    return Action::Down;
-                } else if !self.mouse.r_button().is_down() && self.mouse.r_button().is_changed() {
-                    return Action::Release;
-                } else {
-                    return Action::None;
+            WM_NCHITTEST => {
+                let (titlebar_height, snap_layouts, input_region) = {
+                    let state = data.state.lock().unwrap();
+                    (state.titlebar_height, state.snap_layouts, state.input_region)
+                };
+                if let Some(region) = input_region {
+                    let points = MAKEPOINTS(lparam as u32);
+                    let mut point = POINT {
+                        x: points.x as i32,
+                        y: points.y as i32,
+                    };
+                    ScreenToClient(hwnd, &mut point);
+
+                    if !region.contains(Point::new(point.x, point.y)) {
+                        return HTTRANSPARENT;
+                    }
+                }
+                if let Some(titlebar_height) = titlebar_height {
+                    let hit = DefWindowProcW(hwnd, msg, wparam, lparam);
+                    if hit != HTCLIENT {
+                        return hit;
+                    }
+
+                    let points = MAKEPOINTS(lparam as u32);
+                    let mut point = POINT {
+                        x: points.x as i32,
+                        y: points.y as i32,
+                    };
+                    ScreenToClient(hwnd, &mut point);
+
+                    let resizable = GetWindowLongW(hwnd, GWL_STYLE) as u32 & WS_THICKFRAME != 0;
+                    if resizable && IsZoomed(hwnd) == 0 && point.y < Self::resize_border().1 {
+                        return HTTOP;
+                    } else if point.y < titlebar_height {
+                        // Windows 11 shows the snap layouts only while the cursor is over HTMAXBUTTON
+                        if snap_layouts && Self::in_maximize_button(hwnd, point) {
+                            return HTMAXBUTTON;
+                        }
+                        return HTCAPTION;
+                    }
+
+                    return HTCLIENT;
                 }
             }
 
-            Button::MBUTTON => {
-                if self.mouse.m_button().is_changed() && self.mouse.m_button.is_down() {
-                    return Action::Press;
-                } else if !self.mouse.m_button().is_changed() && self.mouse.m_button.is_down() {
-                    return Action::Down;
-                } else if !self.mouse.m_button().is_down() && self.mouse.m_button().is_changed() {
-                    return Action::Release;
-                } else {
-                    return Action::None;
+            WM_NCLBUTTONDOWN | WM_NCLBUTTONUP if wparam == HTMAXBUTTON as WPARAM => {
+                let custom = {
+                    let state = data.state.lock().unwrap();
+                    state.titlebar_height.is_some() && state.snap_layouts
+                };
+
+                // The default handling would draw the classic button over the custom title bar
+                if custom {
+                    if msg == WM_NCLBUTTONUP {
+                        ShowWindow(hwnd, if IsZoomed(hwnd) != 0 { SW_RESTORE } else { SW_MAXIMIZE });
+                    }
+                    return 0;
                 }
             }
 
-            Button::XBUTTON1 => {
-                if self.mouse.x1_button().is_changed() && self.mouse.x1_button.is_down() {
-                    return Action::Press;
-                } else if !self.mouse.x1_button().is_changed() && self.mouse.x1_button.is_down() {
-                    return Action::Down;
-                } else if !self.mouse.x1_button().is_down() && self.mouse.x1_button().is_changed() {
-                    return Action::Release;
-                } else {
-                    return Action::None;
+            WM_MOUSEACTIVATE => {
+                if LOWORD(lparam as u32) as i16 as LRESULT == HTTRANSPARENT {
+                    return MA_NOACTIVATE as LRESULT;
                 }
             }
 
-            Button::XBUTTON2 => {
-                if self.mouse.x2_button().is_changed() && self.mouse.x2_button.is_down() {
-                    return Action::Press;
-                } else if !self.mouse.x2_button().is_changed() && self.mouse.x2_button.is_down() {
-                    return Action::Down;
-                } else if !self.mouse.x2_button().is_down() && self.mouse.x2_button().is_changed() {
-                    return Action::Release;
-                } else {
-                    return Action::None;
+            WM_NCLBUTTONDBLCLK if wparam == HTCAPTION as WPARAM => {
+                let (custom, maximize) = {
+                    let state = data.state.lock().unwrap();
+                    (state.titlebar_height.is_some(), !state.no_double_click_maximize)
+                };
+
+                if !maximize {
+                    return 0;
+                } else if custom {
+                    ShowWindow(hwnd, if IsZoomed(hwnd) != 0 { SW_RESTORE } else { SW_MAXIMIZE });
+                    return 0;
                 }
             }
 
-            _ => {
-                panic!("There is no virtual mouse button code like {button}");
+            WM_MOVING => {
+                if data.state.lock().unwrap().confine_to_workarea {
+                    let rect = (lparam as *mut RECT).as_mut().unwrap();
+                    let monitor = MonitorFromRect(rect, MONITOR_DEFAULTTONEAREST);
+                    let mut info: MONITORINFO = std::mem::zeroed();
+                    info.cbSize = std::mem::size_of::<MONITORINFO>() as u32;
+
+                    if GetMonitorInfoW(monitor, &mut info) != 0 {
+                        let work = info.rcWork;
+                        let (width, height) = (rect.right - rect.left, rect.bottom - rect.top);
+
+                        let Point { x, y } = Point::new(rect.left, rect.top)
+                            .min(Point::new(work.right - width, work.bottom - height))
+                            .max(Point::new(work.left, work.top));
+
+                        *rect = RECT {
+                            left: x,
+                            top: y,
+                            right: x + width,
+                            bottom: y + height,
+                        };
+
+                        return TRUE as LRESULT;
+                    }
+                }
             }
-        }
-    }
 
-    /// Retrieves the current frame and delta time
-    ///
-    /// # Example
-    ///
-    /// ```ignore
-    /// println!("Time is {}", manager.time().0);
-    /// ```
-    pub fn time(&mut self) -> (f32, f32) {
-        return (self.timer.current_frame, self.timer.dt());
-    }
+            WM_GETDLGCODE => {
+                if data.state.lock().unwrap().want_all_keys {
+                    return DLGC_WANTALLKEYS as LRESULT;
+                }
+            }
 
-    /// If all the windows are closed returns true else return false
+            WM_SETCURSOR => {
+                let cursor = data.state.lock().unwrap().cursor as HCURSOR;
+                // The resize borders keep their own cursors
+                if !cursor.is_null() && LOWORD(lparam as u32) as isize == HTCLIENT {
+                    SetCursor(cursor);
+                    return TRUE as LRESULT;
+                }
+            }
+
+            WM_WINDOWPOSCHANGING => {
+                if data.state.lock().unwrap().always_on_bottom {
+                    // Keeps the window under the others even when it is clicked or another window asks to reorder it
+                    let pos = (lparam as *mut WINDOWPOS).as_mut().unwrap();
+                    pos.hwndInsertAfter = HWND_BOTTOM;
+                }
+            }
+
+            WM_COMMAND => {
+                // lparam is only set by the controls, menus and accelerators leave it empty
+                if lparam == 0 {
+                    data.msger.send(MainEvents::MainWindowEvent {
+                        id: data.id,
+                        event: MainWindowEvents::MenuItem {
+                            id: LOWORD(wparam as u32),
+                        },
+                    });
+                }
+            }
+
+            WM_TIMER => {
+                data.msger.send(MainEvents::MainWindowEvent {
+                    id: data.id,
+                    event: MainWindowEvents::Timer { id: wparam },
+                });
+            }
+
+            WM_DGEWS_SET_TIMER => {
+                SetTimer(hwnd, wparam, lparam as UINT, None);
+                return 0;
+            }
+
+            WM_DGEWS_KILL_TIMER => {
+                KillTimer(hwnd, wparam);
+                return 0;
+            }
+
+            WM_DGEWS_DESTROY => {
+                DestroyWindow(hwnd);
+                return 0;
+            }
+
+            WM_DGEWS_DRAG => {
+                // The mouse capture belongs to this thread, the move or size loop starts only after it is released
+                ReleaseCapture();
+                SendMessageW(hwnd, WM_SYSCOMMAND, wparam, 0);
+                return 0;
+            }
+
+            WM_DGEWS_SYSTEM_MENU => {
+                let points = MAKEPOINTS(lparam as u32);
+                let mut point = POINT {
+                    x: points.x as i32,
+                    y: points.y as i32,
+                };
+                ClientToScreen(hwnd, &mut point);
+                Self::track_system_menu(hwnd, point);
+                return 0;
+            }
+
+            WM_DGEWS_TEXT_INPUT_AREA | WM_IME_STARTCOMPOSITION => {
+                // The IME forgets the position when a new composition starts
+                let area = data.state.lock().unwrap().text_input_area;
+                if let Some(area) = area {
+                    set_text_input_area(hwnd, area);
+                }
+
+                if msg == WM_DGEWS_TEXT_INPUT_AREA {
+                    return 0;
+                }
+            }
+
+            WM_PAINT => {
+                let mut paint: PAINTSTRUCT = std::mem::zeroed();
+                BeginPaint(hwnd, &mut paint);
+                EndPaint(hwnd, &paint);
+
+                // request_redraw() asks for a paint without invalidating anything, then the whole client area is dirty
+                let mut dirty = paint.rcPaint;
+                if IsRectEmpty(&dirty) != 0 {
+                    GetClientRect(hwnd, &mut dirty);
+                }
+
+                data.msger.send(MainEvents::MainWindowEvent {
+                    id: data.id,
+                    event: MainWindowEvents::RedrawRequested {
+                        dirty: Rect::new(dirty.left, dirty.top, dirty.right, dirty.bottom),
+                    },
+                });
+                return 0;
+            }
+
+            _ => {
+                if data.state.lock().unwrap().raw_messages {
+                    data.msger.send(MainEvents::MainRawMessage {
+                        id: data.id,
+                        msg,
+                        wparam,
+                        lparam,
+                    });
+                }
+            }
+        }
+
+        return DefWindowProcW(hwnd, msg, wparam, lparam);
+    }
+
+    // GetKeyState() is up to date only on the window thread while it processes the keyboard messages
+    unsafe fn send_toggles(data: &WindowData, keycodes: &[usize]) {
+        for &keycode in keycodes {
+            if keycode == Key::CAPITAL || keycode == Key::NUMLOCK || keycode == Key::SCROLL {
+                data.msger.send(MainEvents::MainKeyboardEvent {
+                    id: data.id,
+                    event: MainKeyboardEvents::Toggle {
+                        keycode,
+                        on: GetKeyState(keycode as i32) & 1 != 0,
+                    },
+                });
+            }
+        }
+    }
+
+    // Remembers the buttons pressed in the window so that they can be released if their up messages never arrive
+    unsafe fn track_buttons(data: &WindowData, msg: UINT, wparam: WPARAM) {
+        let (button, down) = match msg {
+            WM_LBUTTONDOWN => (MK_LBUTTON, true),
+            WM_LBUTTONUP => (MK_LBUTTON, false),
+            WM_RBUTTONDOWN => (MK_RBUTTON, true),
+            WM_RBUTTONUP => (MK_RBUTTON, false),
+            WM_MBUTTONDOWN => (MK_MBUTTON, true),
+            WM_MBUTTONUP => (MK_MBUTTON, false),
+            WM_XBUTTONDOWN | WM_XBUTTONUP => {
+                let button = if HIWORD(wparam as u32) & XBUTTON1 != 0 {
+                    MK_XBUTTON1
+                } else {
+                    MK_XBUTTON2
+                };
+                (button, msg == WM_XBUTTONDOWN)
+            }
+            _ => return,
+        };
+
+        let mut state = data.state.lock().unwrap();
+        if down {
+            state.held_buttons |= button;
+        } else {
+            state.held_buttons &= !button;
+        }
+    }
+
+    unsafe fn release_held_buttons(data: &WindowData, hwnd: HWND) {
+        let held_buttons = std::mem::take(&mut data.state.lock().unwrap().held_buttons);
+        if held_buttons == 0 {
+            return;
+        }
+
+        let mut cursor: POINT = std::mem::zeroed();
+        GetCursorPos(&mut cursor);
+        ScreenToClient(hwnd, &mut cursor);
+        let pos = Point::new(cursor.x, cursor.y);
+        let modifiers = Modifiers::current();
+
+        let releases = [
+            (MK_LBUTTON, MainMouseEvents::LButton { up: true, pos, modifiers }),
+            (MK_RBUTTON, MainMouseEvents::RButton { up: true, pos, modifiers }),
+            (MK_MBUTTON, MainMouseEvents::MButton { up: true, pos, modifiers }),
+            (
+                MK_XBUTTON1,
+                MainMouseEvents::XButton {
+                    up: true,
+                    wparam: (XBUTTON1 as u32) << 16,
+                    pos,
+                    modifiers,
+                },
+            ),
+            (
+                MK_XBUTTON2,
+                MainMouseEvents::XButton {
+                    up: true,
+                    wparam: (XBUTTON2 as u32) << 16,
+                    pos,
+                    modifiers,
+                },
+            ),
+        ];
+
+        for (button, event) in releases {
+            if held_buttons & button != 0 {
+                data.msger.send(MainEvents::MainMouseEvent { id: data.id, event });
+            }
+        }
+    }
+
+    // The constraints are given for the client area while the system sizes the whole window
+    unsafe fn frame_size(hwnd: HWND) -> Size {
+        let mut window: RECT = std::mem::zeroed();
+        let mut client: RECT = std::mem::zeroed();
+        GetWindowRect(hwnd, &mut window);
+        GetClientRect(hwnd, &mut client);
+
+        if client.right > 0 && client.bottom > 0 {
+            return Size::new(
+                window.right - window.left - client.right,
+                window.bottom - window.top - client.bottom,
+            );
+        }
+
+        // Minimized or not created yet, so the frame is computed from the styles
+        let mut rect: RECT = std::mem::zeroed();
+        AdjustWindowRectEx(
+            &mut rect,
+            GetWindowLongW(hwnd, GWL_STYLE) as u32,
+            FALSE,
+            GetWindowLongW(hwnd, GWL_EXSTYLE) as u32,
+        );
+        return Size::new(rect.right - rect.left, rect.bottom - rect.top);
+    }
+
+    // The custom maximize button is expected where the standard one would be, left of the close button
+    unsafe fn track_system_menu(hwnd: HWND, point: POINT) {
+        let menu = GetSystemMenu(hwnd, FALSE);
+        if menu.is_null() {
+            return;
+        }
+
+        // The default handling which grays the items out runs only for the menu opened by the system
+        let zoomed = IsZoomed(hwnd) != 0;
+        let item = |enabled: bool| if enabled { MF_ENABLED } else { MF_GRAYED };
+        EnableMenuItem(menu, SC_RESTORE as u32, MF_BYCOMMAND | item(zoomed));
+        EnableMenuItem(menu, SC_MOVE as u32, MF_BYCOMMAND | item(!zoomed));
+        EnableMenuItem(menu, SC_SIZE as u32, MF_BYCOMMAND | item(!zoomed));
+        EnableMenuItem(menu, SC_MAXIMIZE as u32, MF_BYCOMMAND | item(!zoomed));
+
+        SetForegroundWindow(hwnd);
+        let command = TrackPopupMenu(
+            menu,
+            TPM_RETURNCMD | TPM_RIGHTBUTTON,
+            point.x,
+            point.y,
+            0,
+            hwnd,
+            std::ptr::null(),
+        );
+        if command != 0 {
+            PostMessageW(hwnd, WM_SYSCOMMAND, command as WPARAM, 0);
+        }
+    }
+
+    unsafe fn in_maximize_button(hwnd: HWND, point: POINT) -> bool {
+        let mut client: RECT = std::mem::zeroed();
+        GetClientRect(hwnd, &mut client);
+        let button_width = GetSystemMetrics(SM_CXSIZE);
+
+        return point.x >= client.right - 2 * button_width && point.x < client.right - button_width;
+    }
+
+    unsafe fn resize_border() -> (i32, i32) {
+        let padding = GetSystemMetrics(SM_CXPADDEDBORDER);
+        return (
+            GetSystemMetrics(SM_CXFRAME) + padding,
+            GetSystemMetrics(SM_CYFRAME) + padding,
+        );
+    }
+
+    unsafe extern "system" fn process_messages(
+        hwnd: HWND,
+        msg: UINT,
+        wparam: WPARAM,
+        lparam: LPARAM,
+    ) -> LRESULT {
+        let data = (GetWindowLongPtrW(hwnd, GWLP_USERDATA) as *const WindowData)
+            .as_ref()
+            .unwrap();
+
+        return Self::catch_panic(data, hwnd, || {
+            Self::track_buttons(data, msg, wparam);
+            return Self::wndproc(data, hwnd, msg, wparam, lparam);
+        });
+    }
+
+    // A panic must not unwind out of the window procedure, so it is sent to the main thread and the window is torn down instead of hanging
+    unsafe fn catch_panic<F>(data: &WindowData, hwnd: HWND, func: F) -> LRESULT
+    where
+        F: FnOnce() -> LRESULT,
+    {
+        let panic = match std::panic::catch_unwind(std::panic::AssertUnwindSafe(func)) {
+            Ok(result) => return result,
+            Err(panic) => panic,
+        };
+
+        let message = match panic.downcast_ref::<&str>() {
+            Some(message) => message.to_string(),
+            None => match panic.downcast_ref::<String>() {
+                Some(message) => message.clone(),
+                None => String::from("unknown panic"),
+            },
+        };
+        data.msger.send(MainEvents::MainError { id: data.id, message });
+
+        // The state may be poisoned now, so the rest of the messages skip the window procedure
+        let default_proc: unsafe extern "system" fn(HWND, UINT, WPARAM, LPARAM) -> LRESULT =
+            DefWindowProcW;
+        SetWindowLongPtrW(hwnd, GWLP_WNDPROC, default_proc as usize as isize);
+        DestroyWindow(hwnd);
+        // WM_DESTROY does not reach the window procedure any more, so the message loop is ended here
+        PostQuitMessage(0);
+        data.msger.send(MainEvents::MainWindowEvent {
+            id: data.id,
+            event: MainWindowEvents::Destroyed,
+        });
+
+        return 0;
+    }
+
+    unsafe extern "system" fn setup(
+        hwnd: HWND,
+        msg: UINT,
+        wparam: WPARAM,
+        lparam: LPARAM,
+    ) -> LRESULT {
+        if msg == WM_CREATE {
+            let create_struct = lparam as *mut CREATESTRUCTW;
+            let data_ptr = create_struct.as_ref().unwrap().lpCreateParams as *const WindowData;
+            let data = data_ptr.as_ref().unwrap();
+            SetWindowLongPtrW(hwnd, GWLP_USERDATA, data_ptr as isize);
+            SetWindowLongPtrW(hwnd, GWLP_WNDPROC, Self::process_messages as isize);
+            let create_struct = create_struct.as_ref().unwrap();
+            let mut client: RECT = std::mem::zeroed();
+            GetClientRect(hwnd, &mut client);
+
+            data.msger.send(MainEvents::MainWindowEvent {
+                id: data.id,
+                event: MainWindowEvents::Create {
+                    size: Size::new(client.right - client.left, client.bottom - client.top),
+                    position: Point::new(create_struct.x, create_struct.y),
+                    scale_factor: scale_factor(hwnd),
+                },
+            });
+            return Self::catch_panic(data, hwnd, || Self::wndproc(data, hwnd, msg, wparam, lparam));
+        }
+
+        return DefWindowProcW(hwnd, msg, wparam, lparam);
+    }
+
+    /// Retrieves typed Keyboard buttons and keys. (Case sensitie!)
+    ///
+    /// # Example
+    ///
+    /// ```ignore
+    /// if manager.get_char(u) {
+    ///     println!("small case u char is typed");
+    /// } else if manager.get_char(T) {
+    ///     println!("capital case T char is typed");
+    /// }
+    /// ```
+    pub fn get_char(&self, char: usize) -> bool {
+        return self.input.get_char(char);
+    }
+
+    /// Retrieves the state of a key in the given window. get_key() returns the state of the focused window since only the focused window gets the keyboard input
+    ///
+    /// # Example
+    ///
+    /// ```ignore
+    /// if let Some(id) = manager.get_window("Editor").map(|window| window.get_id()) {
+    ///     if manager.get_key_for(id, Key::S) == Action::Press {
+    ///         save();
+    ///     }
+    /// }
+    /// ```
+    pub fn get_key_for(&self, id: WindowId, keycode: usize) -> Action {
+        return self.input.get_key_for(id, keycode);
+    }
+
+    /// Returns whether a lock key (Key::CAPITAL, Key::NUMLOCK or Key::SCROLL) is turned on. KeyboardEvents::ToggleChanged is sent when it changes
+    ///
+    /// # Example
+    ///
+    /// ```ignore
+    /// if manager.is_toggled(Key::CAPITAL) {
+    ///     status_bar.show("CAPS");
+    /// }
+    /// ```
+    pub fn is_toggled(&self, keycode: usize) -> bool {
+        return self.input.is_toggled(keycode);
+    }
+
+    /// Retrieves the state of the Keyboard buttons and keys. (Not case sensitive!)
+    ///
+    /// # Example
+    ///
+    /// ```ignore
+    /// if manager.get_key(TAB) == Action::Down {
+    ///     println!("Tabbar is being pressed!");
+    /// }
+    /// ```
+    pub fn get_key(&self, keycode: usize) -> Action {
+        return self.input.get_key(keycode);
+    }
+
+    /// Returns true if any of the keys is held (it has just been pressed or it is down), e.g. for an action which is bound to several keys
+    ///
+    /// # Example
+    ///
+    /// ```ignore
+    /// if manager.any_key_held(&[Key::D, Key::RIGHT]) {
+    ///     player.move_right(dt);
+    /// }
+    /// ```
+    pub fn any_key_held(&self, keycodes: &[usize]) -> bool {
+        return keycodes
+            .iter()
+            .any(|&keycode| matches!(self.get_key(keycode), Action::Press | Action::Down));
+    }
+
+    /// Returns true if any of the keys has just been pressed
+    ///
+    /// # Example
+    ///
+    /// ```ignore
+    /// if manager.any_key_pressed(&[Key::SPACE, Key::W, Key::UP]) {
+    ///     player.jump();
+    /// }
+    /// ```
+    pub fn any_key_pressed(&self, keycodes: &[usize]) -> bool {
+        return keycodes
+            .iter()
+            .any(|&keycode| self.get_key(keycode) == Action::Press);
+    }
+
+    /// Retrieves the raw state of the key as booleans instead of collapsing it into an Action
+    ///
+    /// # Example
+    ///
+    /// ```ignore
+    /// let info = manager.key_info(Key::TAB);
+    /// println!("down: {}, just pressed: {}, just released: {}", info.is_down(), info.just_pressed(), info.just_released());
+    /// ```
+    pub fn key_info(&self, keycode: usize) -> KeyInfo {
+        return self.input.key_info(keycode);
+    }
+
+    /// Turns the text buffer on or off. While it is on, every typed character (including the control ones such as '\r' and '\u{8}') is appended in order to a buffer which is read with take_text_input(), so no character is lost however long a frame takes. Turning it off drops the buffered text
+    ///
+    /// # Example
+    ///
+    /// ```ignore
+    /// manager.enable_text_buffer(true);
+    /// ```
+    pub fn enable_text_buffer(&mut self, enable: bool) {
+        self.input.text_buffer = if enable { Some(String::new()) } else { None };
+        self.input.high_surrogate = None;
+    }
+
+    /// Returns the characters typed since the last call and empties the text buffer. It is always empty if the text buffer has not been enabled with enable_text_buffer()
+    ///
+    /// # Example
+    ///
+    /// ```ignore
+    /// manager.run(|events, control_flow, manager| {
+    ///     text_field.push_str(&manager.take_text_input());
+    /// });
+    /// ```
+    pub fn take_text_input(&mut self) -> String {
+        return self
+            .input
+            .text_buffer
+            .as_mut()
+            .map(std::mem::take)
+            .unwrap_or_default();
+    }
+
+    /// Sets which keyboard events are sent. With KeyEventMode::Transitions only the real presses and releases are sent without repeated Action::Down events. KeyEventMode::Continuous is the default one
+    ///
+    /// # Example
+    ///
+    /// ```ignore
+    /// manager.set_key_event_mode(KeyEventMode::Transitions);
+    ///
+    /// manager.run(|events, control_flow, manager| {
+    ///     match events {
+    ///         Events::KeyboardEvents { id: _, event: KeyboardEvents::Key { keycode, action } } => {
+    ///             println!("{keycode}: {action:?}"); // only Action::Press and Action::Release
+    ///         }
+    ///         _=> {}
+    ///     }
+    /// });
+    /// ```
+    pub fn set_key_event_mode(&mut self, mode: KeyEventMode) {
+        self.input.key_event_mode = mode;
+    }
+
+    /// Sets the cursor which is shown over the client area of every window, including the ones which are added later
+    ///
+    /// # Example
+    ///
+    /// ```ignore
+    /// manager.set_default_cursor(Cursor::Crosshair);
+    /// ```
+    pub fn set_default_cursor(&mut self, cursor: Cursor) {
+        self.default_cursor = Some(cursor);
+        for window in self.windows.values() {
+            window.set_default_cursor(cursor);
+        }
+    }
+
+    /// Sets the minimum client area size which the user can resize every window to, including the ones which are added later. It is the min of the SizeConstraints of the windows, so Window::set_resize_constraints() replaces it for a single window
+    ///
+    /// # Example
+    ///
+    /// ```ignore
+    /// manager.set_min_size_all(640, 480);
+    /// ```
+    pub fn set_min_size_all(&mut self, width: i32, height: i32) {
+        let min_size = Size::new(width, height);
+        self.min_size = Some(min_size);
+        for window in self.windows.values() {
+            window.state.lock().unwrap().constraints.min = Some(min_size);
+        }
+    }
+
+    /// Runs slow work (loading files, generating meshes...) on a fixed pool of worker threads (one per CPU core) so that the windows keep responding. The jobs wait in a queue while every worker is busy, so a job which never returns holds its worker forever. Events::JobFinished { id } is sent when it has finished and the result can be taken from the returned JobHandle
+    ///
+    /// # Example
+    ///
+    /// ```ignore
+    /// let mut job = manager.spawn(|| std::fs::read_to_string("big_file.txt").unwrap());
+    ///
+    /// manager.run(move |events, control_flow, manager| {
+    ///     match events {
+    ///         Events::JobFinished { id } if id == job.id() => {
+    ///             let text = job.poll().unwrap();
+    ///             println!("{} bytes have been read", text.len());
+    ///         }
+    ///         _=> {}
+    ///     }
+    /// });
+    /// ```
+    pub fn spawn<T, F>(&self, func: F) -> JobHandle<T>
+    where
+        T: Send + 'static,
+        F: FnOnce() -> T + Send + 'static,
+    {
+        return JobHandle::spawn(self.msger.clone(), func);
+    }
+
+    /// Synthesizes a key with SendInput() as if it was typed on the keyboard. It goes to the window which has the focus (which may belong to another program). Action::Down sends a repeated key down and Action::None sends nothing
+    ///
+    /// # Example
+    ///
+    /// ```ignore
+    /// manager.send_key(Key::ENTER, Action::Press);
+    /// manager.send_key(Key::ENTER, Action::Release);
+    /// ```
+    pub fn send_key(&self, keycode: usize, action: Action) {
+        let flags = match action {
+            Action::Press | Action::Down => 0,
+            Action::Release => KEYEVENTF_KEYUP,
+            Action::None => return,
+        };
+
+        unsafe {
+            let mut input: INPUT = std::mem::zeroed();
+            input.type_ = INPUT_KEYBOARD;
+            *input.u.ki_mut() = KEYBDINPUT {
+                wVk: keycode as u16,
+                wScan: 0,
+                dwFlags: flags,
+                time: 0,
+                dwExtraInfo: 0,
+            };
+            SendInput(1, &mut input, std::mem::size_of::<INPUT>() as i32);
+        }
+    }
+
+    /// Moves the cursor to the position (in screen coordinates) and synthesizes a mouse button with SendInput() as if it was clicked. Only Action::Press and Action::Release send anything
+    ///
+    /// # Example
+    ///
+    /// ```ignore
+    /// manager.send_mouse(MouseButton::Left, Action::Press, Point::new(200, 150));
+    /// manager.send_mouse(MouseButton::Left, Action::Release, Point::new(200, 150));
+    /// ```
+    pub fn send_mouse(&self, button: MouseButton, action: Action, pos: Point) {
+        let up = match action {
+            Action::Press => false,
+            Action::Release => true,
+            Action::Down | Action::None => return,
+        };
+
+        let (flags, data) = match (button, up) {
+            (MouseButton::Left, false) => (MOUSEEVENTF_LEFTDOWN, 0),
+            (MouseButton::Left, true) => (MOUSEEVENTF_LEFTUP, 0),
+            (MouseButton::Right, false) => (MOUSEEVENTF_RIGHTDOWN, 0),
+            (MouseButton::Right, true) => (MOUSEEVENTF_RIGHTUP, 0),
+            (MouseButton::Middle, false) => (MOUSEEVENTF_MIDDLEDOWN, 0),
+            (MouseButton::Middle, true) => (MOUSEEVENTF_MIDDLEUP, 0),
+            (MouseButton::X1, false) => (MOUSEEVENTF_XDOWN, XBUTTON1),
+            (MouseButton::X1, true) => (MOUSEEVENTF_XUP, XBUTTON1),
+            (MouseButton::X2, false) => (MOUSEEVENTF_XDOWN, XBUTTON2),
+            (MouseButton::X2, true) => (MOUSEEVENTF_XUP, XBUTTON2),
+        };
+
+        unsafe {
+            SetCursorPos(pos.x, pos.y);
+
+            let mut input: INPUT = std::mem::zeroed();
+            input.type_ = INPUT_MOUSE;
+            *input.u.mi_mut() = MOUSEINPUT {
+                dx: 0,
+                dy: 0,
+                mouseData: data as u32,
+                dwFlags: flags,
+                time: 0,
+                dwExtraInfo: 0,
+            };
+            SendInput(1, &mut input, std::mem::size_of::<INPUT>() as i32);
+        }
+    }
+
+    /// Feeds a key straight into the event translation as if the window with the id had received it. Nothing goes through the system, so it works for the automated tests of the input handling without a focused window. The event arrives in run() or wait_event() like a real one
+    ///
+    /// # Example
+    ///
+    /// ```ignore
+    /// let id = manager.window().unwrap().get_id();
+    /// manager.inject_key(id, Key::SPACE, Action::Press);
+    ///
+    /// assert_eq!(
+    ///     manager.wait_event(),
+    ///     Events::KeyboardEvents { id, event: KeyboardEvents::Key { keycode: Key::SPACE, action: Action::Press } }
+    /// );
+    /// ```
+    pub fn inject_key(&self, id: WindowId, keycode: usize, action: Action) {
+        let (up, is_changed) = match action {
+            Action::Press => (false, true),
+            Action::Down => (false, false),
+            Action::Release => (true, true),
+            Action::None => return,
+        };
+
+        self.msger.send(MainEvents::MainKeyboardEvent {
+            id,
+            event: MainKeyboardEvents::Key {
+                up,
+                is_changed,
+                keycode,
+            },
+        });
+    }
+
+    /// Feeds a mouse button straight into the event translation as if the window with the id had received it at the position (in client coordinates). Only Action::Press and Action::Release are fed
+    ///
+    /// # Example
+    ///
+    /// ```ignore
+    /// let id = manager.window().unwrap().get_id();
+    /// manager.inject_mouse(id, MouseButton::Right, Action::Press, Point::new(10, 10));
+    /// ```
+    pub fn inject_mouse(&self, id: WindowId, button: MouseButton, action: Action, pos: Point) {
+        let up = match action {
+            Action::Press => false,
+            Action::Release => true,
+            Action::Down | Action::None => return,
+        };
+        let modifiers = Modifiers::default();
+
+        let event = match button {
+            MouseButton::Left => MainMouseEvents::LButton { up, pos, modifiers },
+            MouseButton::Right => MainMouseEvents::RButton { up, pos, modifiers },
+            MouseButton::Middle => MainMouseEvents::MButton { up, pos, modifiers },
+            // The press is read from the low word and the release from the high word
+            MouseButton::X1 => MainMouseEvents::XButton {
+                up,
+                wparam: ((XBUTTON1 as u32) << 16) | MK_XBUTTON1 as u32,
+                pos,
+                modifiers,
+            },
+            MouseButton::X2 => MainMouseEvents::XButton {
+                up,
+                wparam: ((XBUTTON2 as u32) << 16) | MK_XBUTTON2 as u32,
+                pos,
+                modifiers,
+            },
+        };
+
+        self.msger.send(MainEvents::MainMouseEvent { id, event });
+    }
+
+    /// Sets a callback which sees every event before it is handed to run(), wait_event() or events(), e.g. for logging, metrics or recording. It only observes the events and cannot change them or the control flow. A new callback replaces the previous one
+    ///
+    /// # Example
+    ///
+    /// ```ignore
+    /// let mut log = std::fs::File::create("events.log").unwrap();
+    /// manager.set_event_tap(move |events| {
+    ///     writeln!(log, "{events:?}").unwrap();
+    /// });
+    /// ```
+    pub fn set_event_tap<F>(&mut self, event_tap: F)
+    where
+        F: FnMut(&Events) + Send + 'static,
+    {
+        self.event_tap = Some(EventTap(Box::new(event_tap)));
+    }
+
+    /// Removes the callback which has been set with set_event_tap()
+    ///
+    /// # Example
+    ///
+    /// ```ignore
+    /// manager.remove_event_tap();
+    /// ```
+    pub fn remove_event_tap(&mut self) {
+        self.event_tap = None;
+    }
+
+    /// Sets whether the window messages which the crate does not translate are sent as Events::RawMessage { id, msg, wparam, lparam }. It is useful for debugging and for handling the messages which are not wrapped yet. It is false by default because there are a lot of such messages
+    ///
+    /// # Example
+    ///
+    /// ```ignore
+    /// manager.set_raw_message_passthrough(true);
+    ///
+    /// manager.run(|events, control_flow, manager| {
+    ///     match events {
+    ///         Events::RawMessage { id: _, msg: WM_DISPLAYCHANGE, wparam: _, lparam: _ } => println!("Display resolution has changed"),
+    ///         _=> {}
+    ///     }
+    /// });
+    /// ```
+    pub fn set_raw_message_passthrough(&mut self, enabled: bool) {
+        self.raw_messages = enabled;
+        for window in self.windows.values() {
+            window.state.lock().unwrap().raw_messages = enabled;
+        }
+    }
+
+    /// Sets whether run() returns by itself after the last window has been closed. The closure still gets the WindowEvents::Destroyed of that window before the loop exits. It is true by default
+    ///
+    /// # Example
+    ///
+    /// ```ignore
+    /// // Keep running in the background, e.g. to open a new window later
+    /// manager.set_exit_on_last_window(false);
+    /// ```
+    pub fn set_exit_on_last_window(&mut self, exit: bool) {
+        self.exit_on_last_window = exit;
+    }
+
+    /// Sets how the mouse button events are sent. With MouseEventMode::Unified every button is sent as MouseEvents::Button { button, action, pos, modifiers }. MouseEventMode::Specific is the default one
+    ///
+    /// # Example
+    ///
+    /// ```ignore
+    /// manager.set_mouse_event_mode(MouseEventMode::Unified);
+    ///
+    /// manager.run(|events, control_flow, manager| {
+    ///     match events {
+    ///         Events::MouseEvents { id: _, event: MouseEvents::Button { button, action, .. } } => {
+    ///             println!("{button:?}: {action:?}");
+    ///         }
+    ///         _=> {}
+    ///     }
+    /// });
+    /// ```
+    pub fn set_mouse_event_mode(&mut self, mode: MouseEventMode) {
+        self.input.mouse_event_mode = mode;
+    }
+
+    /// Retrieves the state of the mouse buttons
+    ///
+    /// # Example
+    ///
+    /// ```ignore
+    /// if manager.get_mouse_button(Button::MBUTTON) == Action::Press {
+    ///     *control_flow = ControlFlow::Exit;
+    /// }
+    /// ```
+    pub fn get_mouse_button(&self, button: usize) -> Action {
+        return self.input.get_mouse_button(button);
+    }
+
+    /// Takes a snapshot of the current keyboard and mouse states. It can be stored and then played back with apply_snapshot()
+    ///
+    /// # Example
+    ///
+    /// ```ignore
+    /// let mut replay: Vec<InputSnapshot> = Vec::new();
+    ///
+    /// manager.run(|events, control_flow, manager| {
+    ///     replay.push(manager.snapshot_input());
+    /// });
+    /// ```
+    pub fn snapshot_input(&self) -> InputSnapshot {
+        return self.input.snapshot();
+    }
+
+    /// Returns the input of the frame for an immediate mode GUI: the modifiers, the mouse, the keys, and the scroll delta and the typed text which are both emptied by the call
+    ///
+    /// # Example
+    ///
+    /// ```ignore
+    /// let input = manager.raw_input();
+    /// egui_input.modifiers.ctrl = input.modifiers.control;
+    /// egui_input.events.push(egui::Event::Text(input.text));
+    /// ```
+    pub fn raw_input(&mut self) -> RawInput {
+        return self.input.raw_input();
+    }
+
+    /// Replaces the current keyboard and mouse states with a recorded snapshot so that get_key() and get_mouse_button() return the recorded values
+    ///
+    /// # Example
+    ///
+    /// ```ignore
+    /// let mut frame = 0;
+    ///
+    /// manager.run(|events, control_flow, manager| {
+    ///     manager.apply_snapshot(&replay[frame]);
+    ///     frame += 1;
+    ///
+    ///     if manager.get_key(Key::SPACE) == Action::Press {
+    ///         println!("Space was pressed in the recorded frame");
+    ///     }
+    /// });
+    /// ```
+    pub fn apply_snapshot(&mut self, snapshot: &InputSnapshot) {
+        self.input.apply_snapshot(snapshot);
+    }
+
+    /// Returns the memory and the GDI/USER object counts of the process, e.g. for a debug overlay next to the frame rate
+    ///
+    /// # Example
+    ///
+    /// ```ignore
+    /// let metrics = manager.process_metrics();
+    /// println!("GDI objects: {}", metrics.gdi_objects);
+    /// ```
+    pub fn process_metrics(&self) -> ProcessMetrics {
+        return ProcessMetrics::current();
+    }
+
+    /// Retrieves the time of the current frame and the delta time since the previous one. run() starts a new frame on every pass, so the values stay the same during a frame however often they are read
+    ///
+    /// # Example
+    ///
+    /// ```ignore
+    /// println!("Time is {}", manager.time().0);
+    /// ```
+    pub fn time(&self) -> (f32, f32) {
+        let dt = self.timer.frame_times.back().copied().unwrap_or(0.0f32);
+        return (self.timer.current_frame, dt);
+    }
+
+    /// Limits how many times per second run() goes through the events, so that it sleeps between the frames instead of keeping a CPU core busy. The high resolution timer is used while the limit is set. None (the default) removes the limit
+    ///
+    /// # Example
+    ///
+    /// ```ignore
+    /// manager.set_frame_rate_limit(Some(60.0));
+    /// ```
+    pub fn set_frame_rate_limit(&mut self, frame_rate_limit: Option<f32>) {
+        self.frame_rate_limit = frame_rate_limit.filter(|rate| *rate > 0.0f32);
+        self.frame_limit_resolution = match self.frame_rate_limit {
+            Some(_) => {
+                self.frame_limit_resolution.take().or_else(|| Some(HighResolutionTimer::begin()))
+            }
+            None => None,
+        };
+    }
+
+    /// Sets how long ControlFlow::Wait sleeps at most. When no event arrives for that long the closure is called with Events::AboutToWait, so an idle program still gets a regular tick. None (the default) sleeps until the next event
+    ///
+    /// # Example
+    ///
+    /// ```ignore
+    /// manager.set_wait_timeout(Some(Duration::from_millis(500)));
+    ///
+    /// manager.run(|events, control_flow, manager| {
+    ///     *control_flow = ControlFlow::Wait;
+    ///
+    ///     if events == Events::AboutToWait {
+    ///         text_cursor.toggle_visibility();
+    ///     }
+    /// });
+    /// ```
+    pub fn set_wait_timeout(&mut self, wait_timeout: Option<std::time::Duration>) {
+        self.wait_timeout = wait_timeout;
+    }
+
+    /// Raises the resolution of the system timer to about 1ms until the returned guard is dropped so that the sleeps between the frames are accurate
+    ///
+    /// # Example
+    ///
+    /// ```ignore
+    /// let high_resolution = manager.begin_high_resolution_timer();
+    ///
+    /// manager.run(|events, control_flow, manager| {
+    ///     std::thread::sleep(Duration::from_millis(4));
+    /// });
+    ///
+    /// drop(high_resolution);
+    /// ```
+    pub fn begin_high_resolution_timer(&self) -> HighResolutionTimer {
+        return HighResolutionTimer::begin();
+    }
+
+    /// Retrieves the statistics of the recent frames. A frame is counted on every pass of run()
+    ///
+    /// # Example
+    ///
+    /// ```ignore
+    /// manager.run(|events, control_flow, manager| {
+    ///     let (time, dt) = manager.time();
+    ///     let stats = manager.frame_stats();
+    ///     println!("fps: {}, avg: {}ms", stats.fps, stats.avg_ms);
+    /// });
+    /// ```
+    pub fn frame_stats(&self) -> FrameStats {
+        return self.timer.frame_stats();
+    }
+
+    /// Returns the code run() has exited with: 0 for ControlFlow::Exit (also when the last window has been closed) and the given one for ControlFlow::ExitWithCode. None while run() has not exited
+    ///
+    /// # Example
+    ///
+    /// ```ignore
+    /// manager.run(|events, control_flow, manager| {
+    ///     if failed_to_load {
+    ///         *control_flow = ControlFlow::ExitWithCode(2);
+    ///     }
+    /// });
+    ///
+    /// std::process::exit(manager.exit_code().unwrap_or(0) as i32);
+    /// ```
+    pub fn exit_code(&self) -> Option<u32> {
+        return self.exit_code;
+    }
+
+    /// If all the windows are closed returns true else return false. A window counts as closed only once its WindowEvents::Destroyed has been processed, so a window which is still closing (e.g. after Window::close()) keeps it false
     ///
     /// # Example
     ///
@@ -921,3 +2354,34 @@ impl Manager {
         }
     }
 }
+
+/// An iterator over the events which have already arrived, created with Manager::events()
+///
+/// # Example
+///
+/// ```ignore
+/// let closed = manager
+///     .events()
+///     .filter(|event| matches!(event, Events::WindowEvents { event: WindowEvents::Destroyed, .. }))
+///     .count();
+/// ```
+#[derive(Debug)]
+pub struct EventIter<'a> {
+    manager: &'a mut Manager,
+}
+
+impl<'a> Iterator for EventIter<'a> {
+    type Item = Events;
+
+    fn next(&mut self) -> Option<Events> {
+        while let Ok(main_events) = self.manager.msger.try_recv() {
+            // Some messages only update the input state and are not turned into events
+            let events = self.manager.process(main_events);
+            if events != Events::None {
+                return Some(events);
+            }
+        }
+
+        return None;
+    }
+}
@@ -0,0 +1,350 @@
+use std::time::Duration;
+
+use crate::prelude::*;
+
+/// The ManagerBuilder collects the settings of the whole process and of the event loop which have to be applied before the first window is created (the later setters of the Manager may be too late for them)
+///
+/// # Example
+///
+/// ```ignore
+/// let manager = ManagerBuilder::new()
+///     .with_dpi_aware(true)
+///     .with_app_user_model_id("MrTitanHearted.DGEWS.Demo")
+///     .with_frame_rate_limit(Some(60.0))
+///     .build(WindowBuilder::default())
+///     .unwrap();
+/// ```
+#[derive(Clone, PartialEq, Debug)]
+pub struct ManagerBuilder {
+    pub(crate) dpi_aware: bool,
+    pub(crate) app_user_model_id: Option<String>,
+    pub(crate) high_resolution_timer: bool,
+    pub(crate) frame_rate_limit: Option<f32>,
+    pub(crate) wait_timeout: Option<Duration>,
+    pub(crate) exit_on_last_window: bool,
+    pub(crate) default_cursor: Option<Cursor>,
+    pub(crate) min_size: Option<Size>,
+    pub(crate) key_event_mode: KeyEventMode,
+    pub(crate) mouse_event_mode: MouseEventMode,
+    pub(crate) raw_messages: bool,
+}
+
+impl Default for ManagerBuilder {
+    fn default() -> Self {
+        return Self {
+            dpi_aware: false,
+            app_user_model_id: None,
+            high_resolution_timer: false,
+            frame_rate_limit: None,
+            wait_timeout: None,
+            exit_on_last_window: true,
+            default_cursor: None,
+            min_size: None,
+            key_event_mode: KeyEventMode::default(),
+            mouse_event_mode: MouseEventMode::default(),
+            raw_messages: false,
+        };
+    }
+}
+
+impl ManagerBuilder {
+    /// Creates a new instance of the ManagerBuilder with the same settings as Manager::new() uses
+    ///
+    /// # Example
+    ///
+    /// ```ignore
+    /// let manager_builder = ManagerBuilder::new();
+    /// assert_eq!(manager_builder.is_exit_on_last_window(), true);
+    /// ```
+    pub fn new() -> Self {
+        return Self::default();
+    }
+
+    /// Returns a ManagerBuilder which makes the process DPI aware, so the system does not stretch (and blur) the windows on the monitors with scaling
+    ///
+    /// # Example
+    ///
+    /// ```ignore
+    /// let manager_builder = ManagerBuilder::new().with_dpi_aware(true);
+    /// assert_eq!(manager_builder.is_dpi_aware(), true);
+    /// ```
+    pub fn with_dpi_aware(mut self, dpi_aware: bool) -> Self {
+        self.dpi_aware = dpi_aware;
+        return self;
+    }
+
+    /// Returns whether the process is made DPI aware
+    ///
+    /// # Example
+    ///
+    /// ```ignore
+    /// let manager_builder = ManagerBuilder::new();
+    /// assert_eq!(manager_builder.is_dpi_aware(), false);
+    /// ```
+    pub fn is_dpi_aware(&self) -> bool {
+        return self.dpi_aware;
+    }
+
+    /// Returns a ManagerBuilder which sets the AppUserModelID of the process before the first window is created (see Manager::set_app_user_model_id())
+    ///
+    /// # Example
+    ///
+    /// ```ignore
+    /// let manager_builder = ManagerBuilder::new().with_app_user_model_id("MrTitanHearted.DGEWS.Demo");
+    /// assert_eq!(manager_builder.get_app_user_model_id(), Some(String::from("MrTitanHearted.DGEWS.Demo")));
+    /// ```
+    pub fn with_app_user_model_id(mut self, id: &str) -> Self {
+        self.app_user_model_id = Some(id.to_string());
+        return self;
+    }
+
+    /// Returns the AppUserModelID which is set
+    ///
+    /// # Example
+    ///
+    /// ```ignore
+    /// let manager_builder = ManagerBuilder::new();
+    /// assert_eq!(manager_builder.get_app_user_model_id(), None);
+    /// ```
+    pub fn get_app_user_model_id(&self) -> Option<String> {
+        return self.app_user_model_id.clone();
+    }
+
+    /// Returns a ManagerBuilder whose Manager keeps the resolution of the system timer at about 1ms for as long as it lives (see Manager::begin_high_resolution_timer())
+    ///
+    /// # Example
+    ///
+    /// ```ignore
+    /// let manager_builder = ManagerBuilder::new().with_high_resolution_timer(true);
+    /// assert_eq!(manager_builder.is_high_resolution_timer(), true);
+    /// ```
+    pub fn with_high_resolution_timer(mut self, high_resolution_timer: bool) -> Self {
+        self.high_resolution_timer = high_resolution_timer;
+        return self;
+    }
+
+    /// Returns whether the Manager keeps the high resolution of the system timer
+    ///
+    /// # Example
+    ///
+    /// ```ignore
+    /// let manager_builder = ManagerBuilder::new();
+    /// assert_eq!(manager_builder.is_high_resolution_timer(), false);
+    /// ```
+    pub fn is_high_resolution_timer(&self) -> bool {
+        return self.high_resolution_timer;
+    }
+
+    /// Returns a ManagerBuilder with a frame rate limit (see Manager::set_frame_rate_limit())
+    ///
+    /// # Example
+    ///
+    /// ```ignore
+    /// let manager_builder = ManagerBuilder::new().with_frame_rate_limit(Some(144.0));
+    /// assert_eq!(manager_builder.get_frame_rate_limit(), Some(144.0));
+    /// ```
+    pub fn with_frame_rate_limit(mut self, frame_rate_limit: Option<f32>) -> Self {
+        self.frame_rate_limit = frame_rate_limit;
+        return self;
+    }
+
+    /// Returns the frame rate limit
+    ///
+    /// # Example
+    ///
+    /// ```ignore
+    /// let manager_builder = ManagerBuilder::new();
+    /// assert_eq!(manager_builder.get_frame_rate_limit(), None);
+    /// ```
+    pub fn get_frame_rate_limit(&self) -> Option<f32> {
+        return self.frame_rate_limit;
+    }
+
+    /// Returns a ManagerBuilder with the longest sleep of ControlFlow::Wait (see Manager::set_wait_timeout())
+    ///
+    /// # Example
+    ///
+    /// ```ignore
+    /// let manager_builder = ManagerBuilder::new().with_wait_timeout(Some(Duration::from_millis(500)));
+    /// assert_eq!(manager_builder.get_wait_timeout(), Some(Duration::from_millis(500)));
+    /// ```
+    pub fn with_wait_timeout(mut self, wait_timeout: Option<Duration>) -> Self {
+        self.wait_timeout = wait_timeout;
+        return self;
+    }
+
+    /// Returns the longest sleep of ControlFlow::Wait
+    ///
+    /// # Example
+    ///
+    /// ```ignore
+    /// let manager_builder = ManagerBuilder::new();
+    /// assert_eq!(manager_builder.get_wait_timeout(), None);
+    /// ```
+    pub fn get_wait_timeout(&self) -> Option<Duration> {
+        return self.wait_timeout;
+    }
+
+    /// Returns a ManagerBuilder whose run() returns by itself after the last window has been closed or not (see Manager::set_exit_on_last_window())
+    ///
+    /// # Example
+    ///
+    /// ```ignore
+    /// let manager_builder = ManagerBuilder::new().with_exit_on_last_window(false);
+    /// assert_eq!(manager_builder.is_exit_on_last_window(), false);
+    /// ```
+    pub fn with_exit_on_last_window(mut self, exit: bool) -> Self {
+        self.exit_on_last_window = exit;
+        return self;
+    }
+
+    /// Returns whether run() returns by itself after the last window has been closed
+    ///
+    /// # Example
+    ///
+    /// ```ignore
+    /// let manager_builder = ManagerBuilder::new();
+    /// assert_eq!(manager_builder.is_exit_on_last_window(), true);
+    /// ```
+    pub fn is_exit_on_last_window(&self) -> bool {
+        return self.exit_on_last_window;
+    }
+
+    /// Returns a ManagerBuilder with the cursor of every window (see Manager::set_default_cursor())
+    ///
+    /// # Example
+    ///
+    /// ```ignore
+    /// let manager_builder = ManagerBuilder::new().with_default_cursor(Cursor::Crosshair);
+    /// assert_eq!(manager_builder.get_default_cursor(), Some(Cursor::Crosshair));
+    /// ```
+    pub fn with_default_cursor(mut self, cursor: Cursor) -> Self {
+        self.default_cursor = Some(cursor);
+        return self;
+    }
+
+    /// Returns the cursor of every window
+    ///
+    /// # Example
+    ///
+    /// ```ignore
+    /// let manager_builder = ManagerBuilder::new();
+    /// assert_eq!(manager_builder.get_default_cursor(), None);
+    /// ```
+    pub fn get_default_cursor(&self) -> Option<Cursor> {
+        return self.default_cursor;
+    }
+
+    /// Returns a ManagerBuilder with the minimum client area size of every window (see Manager::set_min_size_all())
+    ///
+    /// # Example
+    ///
+    /// ```ignore
+    /// let manager_builder = ManagerBuilder::new().with_min_size(640, 480);
+    /// assert_eq!(manager_builder.get_min_size(), Some(Size::new(640, 480)));
+    /// ```
+    pub fn with_min_size(mut self, width: i32, height: i32) -> Self {
+        self.min_size = Some(Size::new(width, height));
+        return self;
+    }
+
+    /// Returns the minimum client area size of every window
+    ///
+    /// # Example
+    ///
+    /// ```ignore
+    /// let manager_builder = ManagerBuilder::new();
+    /// assert_eq!(manager_builder.get_min_size(), None);
+    /// ```
+    pub fn get_min_size(&self) -> Option<Size> {
+        return self.min_size;
+    }
+
+    /// Returns a ManagerBuilder with the mode of the key events (see Manager::set_key_event_mode())
+    ///
+    /// # Example
+    ///
+    /// ```ignore
+    /// let manager_builder = ManagerBuilder::new().with_key_event_mode(mode);
+    /// assert_eq!(manager_builder.get_key_event_mode(), mode);
+    /// ```
+    pub fn with_key_event_mode(mut self, mode: KeyEventMode) -> Self {
+        self.key_event_mode = mode;
+        return self;
+    }
+
+    /// Returns the mode of the key events
+    ///
+    /// # Example
+    ///
+    /// ```ignore
+    /// let manager_builder = ManagerBuilder::new();
+    /// assert_eq!(manager_builder.get_key_event_mode(), KeyEventMode::default());
+    /// ```
+    pub fn get_key_event_mode(&self) -> KeyEventMode {
+        return self.key_event_mode;
+    }
+
+    /// Returns a ManagerBuilder with the mode of the mouse button events (see Manager::set_mouse_event_mode())
+    ///
+    /// # Example
+    ///
+    /// ```ignore
+    /// let manager_builder = ManagerBuilder::new().with_mouse_event_mode(MouseEventMode::Unified);
+    /// assert_eq!(manager_builder.get_mouse_event_mode(), MouseEventMode::Unified);
+    /// ```
+    pub fn with_mouse_event_mode(mut self, mode: MouseEventMode) -> Self {
+        self.mouse_event_mode = mode;
+        return self;
+    }
+
+    /// Returns the mode of the mouse button events
+    ///
+    /// # Example
+    ///
+    /// ```ignore
+    /// let manager_builder = ManagerBuilder::new();
+    /// assert_eq!(manager_builder.get_mouse_event_mode(), MouseEventMode::Specific);
+    /// ```
+    pub fn get_mouse_event_mode(&self) -> MouseEventMode {
+        return self.mouse_event_mode;
+    }
+
+    /// Returns a ManagerBuilder which sends the untranslated window messages as Events::RawMessage or not (see Manager::set_raw_message_passthrough())
+    ///
+    /// # Example
+    ///
+    /// ```ignore
+    /// let manager_builder = ManagerBuilder::new().with_raw_message_passthrough(true);
+    /// assert_eq!(manager_builder.is_raw_message_passthrough(), true);
+    /// ```
+    pub fn with_raw_message_passthrough(mut self, enabled: bool) -> Self {
+        self.raw_messages = enabled;
+        return self;
+    }
+
+    /// Returns whether the untranslated window messages are sent as Events::RawMessage
+    ///
+    /// # Example
+    ///
+    /// ```ignore
+    /// let manager_builder = ManagerBuilder::new();
+    /// assert_eq!(manager_builder.is_raw_message_passthrough(), false);
+    /// ```
+    pub fn is_raw_message_passthrough(&self) -> bool {
+        return self.raw_messages;
+    }
+
+    /// Applies the settings and creates the Manager with its first window. It fails if the AppUserModelID cannot be set or the window cannot be created (see Manager::try_new())
+    ///
+    /// # Example
+    ///
+    /// ```ignore
+    /// let mut manager = ManagerBuilder::new()
+    ///     .with_dpi_aware(true)
+    ///     .build(WindowBuilder::default().with_title("Editor"))?;
+    /// ```
+    pub fn build(self, builder: WindowBuilder) -> Result<Manager, WindowError> {
+        return Manager::from_builder(self, builder);
+    }
+}
@@ -0,0 +1,212 @@
+use winapi::{
+    shared::windef::{HACCEL, HMENU},
+    um::winuser::*,
+};
+
+use crate::prelude::*;
+
+#[derive(Debug, Clone, PartialEq)]
+pub(crate) enum MenuEntry {
+    Item { id: u16, title: String },
+    Separator,
+    SubMenu { title: String, menu: Menu },
+}
+
+/// A menu bar (or a drop-down menu of it) of the window. Every item has its own id which is sent with WindowEvents::MenuItem { id } when the item is clicked
+///
+/// # Example
+///
+/// ```ignore
+/// let menu = Menu::new()
+///     .with_submenu("&File", Menu::new()
+///         .with_item(1, "&Open\tCtrl+O")
+///         .with_separator()
+///         .with_item(2, "E&xit"))
+///     .with_item(3, "&About");
+///
+/// manager.window().unwrap().set_menu(menu);
+/// ```
+#[derive(Default, Debug, Clone, PartialEq)]
+pub struct Menu {
+    pub(crate) entries: Vec<MenuEntry>,
+}
+
+impl Menu {
+    /// Creates a new empty menu
+    ///
+    /// # Example
+    ///
+    /// ```ignore
+    /// let menu = Menu::new();
+    /// ```
+    pub fn new() -> Self {
+        return Self::default();
+    }
+
+    /// Returns the menu with a new item. '&' marks the mnemonic character and '\t' separates the shortcut text
+    ///
+    /// # Example
+    ///
+    /// ```ignore
+    /// let menu = Menu::new().with_item(1, "&Save\tCtrl+S");
+    /// ```
+    pub fn with_item(mut self, id: u16, title: &str) -> Self {
+        self.entries.push(MenuEntry::Item {
+            id,
+            title: title.to_owned(),
+        });
+        return self;
+    }
+
+    /// Returns the menu with a separator line
+    ///
+    /// # Example
+    ///
+    /// ```ignore
+    /// let menu = Menu::new()
+    ///     .with_item(1, "Open")
+    ///     .with_separator()
+    ///     .with_item(2, "Exit");
+    /// ```
+    pub fn with_separator(mut self) -> Self {
+        self.entries.push(MenuEntry::Separator);
+        return self;
+    }
+
+    /// Returns the menu with a drop-down submenu
+    ///
+    /// # Example
+    ///
+    /// ```ignore
+    /// let menu = Menu::new().with_submenu("&Edit", Menu::new()
+    ///     .with_item(10, "&Undo")
+    ///     .with_item(11, "&Redo"));
+    /// ```
+    pub fn with_submenu(mut self, title: &str, menu: Menu) -> Self {
+        self.entries.push(MenuEntry::SubMenu {
+            title: title.to_owned(),
+            menu,
+        });
+        return self;
+    }
+
+    pub(crate) unsafe fn build(&self, popup: bool) -> HMENU {
+        let hmenu = if popup { CreatePopupMenu() } else { CreateMenu() };
+
+        for entry in &self.entries {
+            match entry {
+                MenuEntry::Item { id, title } => {
+                    let title = Wstring::from(title);
+                    AppendMenuW(hmenu, MF_STRING, *id as usize, title.as_ptr());
+                }
+                MenuEntry::Separator => {
+                    AppendMenuW(hmenu, MF_SEPARATOR, 0, std::ptr::null());
+                }
+                MenuEntry::SubMenu { title, menu } => {
+                    let title = Wstring::from(title);
+                    AppendMenuW(hmenu, MF_POPUP, menu.build(true) as usize, title.as_ptr());
+                }
+            }
+        }
+
+        return hmenu;
+    }
+}
+
+/// A keyboard shortcut which sends WindowEvents::MenuItem { id } the same way as clicking a menu item
+///
+/// # Example
+///
+/// ```ignore
+/// manager.window().unwrap().set_accelerators(&[
+///     Accelerator::new(Key::O, 1).with_control(true),
+///     Accelerator::new(Key::F4, 2).with_alt(true),
+/// ]);
+/// ```
+#[derive(Default, Debug, Copy, Clone, PartialEq, Eq)]
+pub struct Accelerator {
+    pub(crate) keycode: usize,
+    pub(crate) id: u16,
+    pub(crate) control: bool,
+    pub(crate) shift: bool,
+    pub(crate) alt: bool,
+}
+
+impl Accelerator {
+    /// Creates a new accelerator for the key without any modifiers
+    ///
+    /// # Example
+    ///
+    /// ```ignore
+    /// let accelerator = Accelerator::new(Key::F5, 7);
+    /// ```
+    pub fn new(keycode: usize, id: u16) -> Self {
+        return Self {
+            keycode,
+            id,
+            ..Default::default()
+        };
+    }
+
+    /// Returns the accelerator which requires the control key as well
+    ///
+    /// # Example
+    ///
+    /// ```ignore
+    /// let accelerator = Accelerator::new(Key::S, 1).with_control(true);
+    /// ```
+    pub fn with_control(mut self, control: bool) -> Self {
+        self.control = control;
+        return self;
+    }
+
+    /// Returns the accelerator which requires the shift key as well
+    ///
+    /// # Example
+    ///
+    /// ```ignore
+    /// let accelerator = Accelerator::new(Key::S, 1).with_shift(true);
+    /// ```
+    pub fn with_shift(mut self, shift: bool) -> Self {
+        self.shift = shift;
+        return self;
+    }
+
+    /// Returns the accelerator which requires the alt key as well
+    ///
+    /// # Example
+    ///
+    /// ```ignore
+    /// let accelerator = Accelerator::new(Key::S, 1).with_alt(true);
+    /// ```
+    pub fn with_alt(mut self, alt: bool) -> Self {
+        self.alt = alt;
+        return self;
+    }
+
+    pub(crate) unsafe fn build_table(accelerators: &[Accelerator]) -> HACCEL {
+        let mut table: Vec<ACCEL> = accelerators
+            .iter()
+            .map(|accelerator| {
+                let mut flags = FVIRTKEY;
+                if accelerator.control {
+                    flags |= FCONTROL;
+                }
+                if accelerator.shift {
+                    flags |= FSHIFT;
+                }
+                if accelerator.alt {
+                    flags |= FALT;
+                }
+
+                ACCEL {
+                    fVirt: flags,
+                    key: accelerator.keycode as u16,
+                    cmd: accelerator.id,
+                }
+            })
+            .collect();
+
+        return CreateAcceleratorTableW(table.as_mut_ptr(), table.len() as i32);
+    }
+}
@@ -0,0 +1,55 @@
+use winapi::{
+    shared::minwindef::DWORD,
+    um::{
+        processthreadsapi::GetCurrentProcess,
+        psapi::{GetProcessMemoryInfo, PROCESS_MEMORY_COUNTERS},
+        winnt::HANDLE,
+    },
+};
+
+// winapi does not declare GetGuiResources()
+#[link(name = "user32")]
+extern "system" {
+    fn GetGuiResources(process: HANDLE, flags: DWORD) -> DWORD;
+}
+
+const GR_GDIOBJECTS: DWORD = 0;
+const GR_USEROBJECTS: DWORD = 1;
+
+/// Memory and handle counts of the process for a debug overlay. The GDI and USER object counts which keep growing usually mean that icons, brushes or cursors are leaked
+///
+/// # Example
+///
+/// ```ignore
+/// let metrics = manager.process_metrics();
+/// println!("{} MB, {} GDI objects, {} USER objects",
+///     metrics.working_set_bytes / 1024 / 1024, metrics.gdi_objects, metrics.user_objects);
+/// ```
+#[derive(Default, Debug, Copy, Clone, PartialEq, Eq, Hash)]
+pub struct ProcessMetrics {
+    /// Physical memory used by the process in bytes
+    pub working_set_bytes: usize,
+    /// Number of the GDI objects (bitmaps, brushes, fonts...) the process owns
+    pub gdi_objects: u32,
+    /// Number of the USER objects (windows, menus, icons, cursors...) the process owns
+    pub user_objects: u32,
+}
+
+impl ProcessMetrics {
+    pub(crate) fn current() -> Self {
+        unsafe {
+            let process = GetCurrentProcess();
+
+            let mut counters: PROCESS_MEMORY_COUNTERS = std::mem::zeroed();
+            counters.cb = std::mem::size_of::<PROCESS_MEMORY_COUNTERS>() as DWORD;
+            // The working set stays 0 if the counters cannot be read
+            GetProcessMemoryInfo(process, &mut counters, counters.cb);
+
+            return Self {
+                working_set_bytes: counters.WorkingSetSize,
+                gdi_objects: GetGuiResources(process, GR_GDIOBJECTS),
+                user_objects: GetGuiResources(process, GR_USEROBJECTS),
+            };
+        }
+    }
+}
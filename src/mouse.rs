@@ -37,6 +37,32 @@ impl Mouse {
         self.x2_button.set_changed(false);
     }
 
+    pub(crate) fn state(&self) -> MouseState {
+        return MouseState {
+            x: self.x,
+            y: self.y,
+            last_x: self.last_x,
+            last_y: self.last_y,
+            l_button: self.l_button.action(),
+            r_button: self.r_button.action(),
+            m_button: self.m_button.action(),
+            x1_button: self.x1_button.action(),
+            x2_button: self.x2_button.action(),
+        };
+    }
+
+    pub(crate) fn set_state(&mut self, state: &MouseState) {
+        self.x = state.x;
+        self.y = state.y;
+        self.last_x = state.last_x;
+        self.last_y = state.last_y;
+        self.l_button = KeyState::from_action(state.l_button);
+        self.r_button = KeyState::from_action(state.r_button);
+        self.m_button = KeyState::from_action(state.m_button);
+        self.x1_button = KeyState::from_action(state.x1_button);
+        self.x2_button = KeyState::from_action(state.x2_button);
+    }
+
     pub(crate) fn update_pos(&mut self, x: i16, y: i16) {
         self.last_x = self.x;
         self.last_y = self.y;
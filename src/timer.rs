@@ -1,4 +1,6 @@
-use std::time::*;
+use std::{collections::VecDeque, time::*};
+
+use winapi::um::timeapi::{timeBeginPeriod, timeEndPeriod};
 
 /// Timer sturct to retrieve current time
 /// 
@@ -12,13 +14,20 @@ use std::time::*;
 /// time.update();
 /// println!("Time: {}", time.time());
 /// ```
-#[derive(Clone, Copy, Debug, PartialEq)]
+#[derive(Clone, Debug, PartialEq)]
 pub struct Timer {
     pub(crate) instant: Instant,
     pub(crate) current_frame: f32,
+    pub(crate) frame_count: u64,
+    pub(crate) frame_times: VecDeque<f32>,
+    pub(crate) frame_window: usize,
+    pub(crate) laps: Vec<(String, f32)>,
+    pub(crate) last_lap: f32,
 }
 
 impl Timer {
+    const DEFAULT_FRAME_WINDOW: usize = 60;
+
     /// Creates a new instance of the Time struct
     /// 
     /// # Example
@@ -30,6 +39,11 @@ impl Timer {
         return Self {
             instant: Instant::now(),
             current_frame: 0.0f32,
+            frame_count: 0u64,
+            frame_times: VecDeque::with_capacity(Self::DEFAULT_FRAME_WINDOW),
+            frame_window: Self::DEFAULT_FRAME_WINDOW,
+            laps: Vec::new(),
+            last_lap: 0.0f32,
         };
     }
 
@@ -45,7 +59,18 @@ impl Timer {
     /// timer.update();
     /// ```
     pub fn update(&mut self) {
-        self.current_frame = self.time();
+        let time = self.time();
+
+        if self.frame_times.len() == self.frame_window {
+            self.frame_times.pop_front();
+        }
+        self.frame_times.push_back(time - self.current_frame);
+
+        self.frame_count += 1;
+        self.current_frame = time;
+
+        self.laps.clear();
+        self.last_lap = time;
     }
     
     /// Retrieves the delta time. (dt() function updates the this instance as well)
@@ -98,4 +123,171 @@ impl Timer {
     pub fn time(&self) -> f32 {
         return self.instant.elapsed().as_secs_f32();
     }
+
+    /// Retrieves how many times the timer has been updated (in other words, the number of frames)
+    /// 
+    /// # Example
+    /// 
+    /// ```ignore
+    /// let mut timer = Timer::new();
+    /// timer.update();
+    /// timer.update();
+    /// assert_eq!(timer.frame_count(), 2);
+    /// ```
+    pub fn frame_count(&self) -> u64 {
+        return self.frame_count;
+    }
+
+    /// Retrieves the average time of the recent frames in seconds. The number of the frames can be changed with set_frame_window()
+    /// 
+    /// # Example
+    /// 
+    /// ```ignore
+    /// println!("Average frame time: {}s", timer.average_frame_time());
+    /// ```
+    pub fn average_frame_time(&self) -> f32 {
+        if self.frame_times.is_empty() {
+            return 0.0f32;
+        }
+
+        return self.frame_times.iter().sum::<f32>() / self.frame_times.len() as f32;
+    }
+
+    /// Sets how many recent frames are used for the frame statistics (at least 1). It is 60 by default
+    /// 
+    /// # Example
+    /// 
+    /// ```ignore
+    /// let mut timer = Timer::new();
+    /// timer.set_frame_window(120);
+    /// ```
+    pub fn set_frame_window(&mut self, frames: usize) {
+        self.frame_window = frames.max(1);
+        while self.frame_times.len() > self.frame_window {
+            self.frame_times.pop_front();
+        }
+    }
+
+    /// Records the time in milliseconds which has passed since the previous lap (or since the last update() for the first lap of a frame) under the given label. The laps are cleared when the timer is updated
+    /// 
+    /// # Example
+    /// 
+    /// ```ignore
+    /// timer.update();
+    /// handle_input();
+    /// timer.lap("input");
+    /// update_world();
+    /// timer.lap("update");
+    /// ```
+    pub fn lap(&mut self, label: &str) {
+        let time = self.time();
+        self.laps.push((label.to_string(), (time - self.last_lap) * 1000.0f32));
+        self.last_lap = time;
+    }
+
+    /// Retrieves the laps of the current frame as (label, milliseconds) pairs
+    /// 
+    /// # Example
+    /// 
+    /// ```ignore
+    /// for (label, ms) in timer.laps() {
+    ///     println!("{}: {}ms", label, ms);
+    /// }
+    /// ```
+    pub fn laps(&self) -> &[(String, f32)] {
+        return &self.laps;
+    }
+
+    /// Retrieves the statistics of the recent frames
+    /// 
+    /// # Example
+    /// 
+    /// ```ignore
+    /// let stats = timer.frame_stats();
+    /// println!("fps: {}, avg: {}ms, min: {}ms, max: {}ms", stats.fps, stats.avg_ms, stats.min_ms, stats.max_ms);
+    /// ```
+    pub fn frame_stats(&self) -> FrameStats {
+        let avg_ms = self.average_frame_time() * 1000.0f32;
+        let min_ms = self.frame_times.iter().copied().fold(f32::INFINITY, f32::min);
+        let max_ms = self.frame_times.iter().copied().fold(0.0f32, f32::max);
+
+        return FrameStats {
+            count: self.frame_count,
+            fps: if avg_ms > 0.0f32 { 1000.0f32 / avg_ms } else { 0.0f32 },
+            avg_ms,
+            min_ms: if min_ms.is_finite() { min_ms * 1000.0f32 } else { 0.0f32 },
+            max_ms: max_ms * 1000.0f32,
+        };
+    }
+}
+
+/// Statistics of the recent frames which are retrieved from the Timer
+/// 
+/// # Example
+/// 
+/// ```ignore
+/// let stats = manager.frame_stats();
+/// println!("{} frames, {} fps", stats.count, stats.fps);
+/// ```
+#[derive(Default, Clone, Copy, Debug, PartialEq)]
+pub struct FrameStats {
+    /// Number of all the frames
+    pub count: u64,
+    /// Frames per second computed from the average frame time
+    pub fps: f32,
+    /// Average frame time in milliseconds
+    pub avg_ms: f32,
+    /// Shortest frame time in milliseconds
+    pub min_ms: f32,
+    /// Longest frame time in milliseconds
+    pub max_ms: f32,
+}
+
+/// Raises the resolution of the system timer (and so of std::thread::sleep()) to about 1ms for as long as it is alive. The default resolution is about 15ms which makes a frame limiter built on sleeping overshoot badly. The previous resolution is restored when the guard is dropped
+/// 
+/// # Example
+/// 
+/// ```ignore
+/// let _high_resolution = manager.begin_high_resolution_timer();
+/// 
+/// manager.run(|events, control_flow, manager| {
+///     std::thread::sleep(Duration::from_millis(4)); // sleeps ~4ms instead of ~15ms
+/// });
+/// ```
+#[derive(Debug)]
+pub struct HighResolutionTimer {
+    period: u32,
+}
+
+impl HighResolutionTimer {
+    const PERIOD: u32 = 1;
+
+    pub(crate) fn begin() -> Self {
+        unsafe {
+            timeBeginPeriod(Self::PERIOD);
+        }
+
+        return Self {
+            period: Self::PERIOD,
+        };
+    }
+
+    /// Restores the previous resolution of the system timer. It is the same as dropping the guard
+    /// 
+    /// # Example
+    /// 
+    /// ```ignore
+    /// let high_resolution = manager.begin_high_resolution_timer();
+    /// // ... the time critical part ...
+    /// high_resolution.end();
+    /// ```
+    pub fn end(self) {}
+}
+
+impl Drop for HighResolutionTimer {
+    fn drop(&mut self) {
+        unsafe {
+            timeEndPeriod(self.period);
+        }
+    }
 }
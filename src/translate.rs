@@ -0,0 +1,801 @@
+use std::collections::HashMap;
+
+use winapi::{
+    shared::minwindef::{HIWORD, LOWORD},
+    um::winuser::{MK_XBUTTON1, MK_XBUTTON2, XBUTTON1, XBUTTON2},
+};
+
+use crate::prelude::*;
+
+/// Keyboard and mouse states that are updated by translate(). The Manager owns one and the getters such as get_key() read from it
+#[derive(Default, Debug, Clone, PartialEq)]
+pub(crate) struct InputState {
+    pub(crate) keyboard: Keyboard,
+    pub(crate) mouse: Mouse,
+    pub(crate) key_event_mode: KeyEventMode,
+    pub(crate) mouse_event_mode: MouseEventMode,
+    pub(crate) text_buffer: Option<String>,
+    pub(crate) high_surrogate: Option<u16>,
+    pub(crate) toggled: HashMap<usize, bool>,
+    pub(crate) focused: Option<WindowId>,
+    pub(crate) keyboards: HashMap<WindowId, Keyboard>,
+    pub(crate) scroll_delta: i32,
+}
+
+impl InputState {
+    pub(crate) fn new() -> Self {
+        return Self {
+            keyboard: Keyboard::new(false),
+            mouse: Mouse::new(),
+            key_event_mode: KeyEventMode::default(),
+            mouse_event_mode: MouseEventMode::default(),
+            text_buffer: None,
+            high_surrogate: None,
+            toggled: HashMap::new(),
+            focused: None,
+            keyboards: HashMap::new(),
+            scroll_delta: 0,
+        };
+    }
+
+    // The windows live in their own threads, so the SetFocus of the new window can arrive before the LostFocus of the old one
+    pub(crate) fn lose_focus(&mut self, id: WindowId) {
+        if self.focused == Some(id) {
+            self.focused = None;
+        }
+    }
+
+    // WM_CHAR gives UTF-16 units, so the characters outside of the BMP (e.g. emojis) come as two messages
+    pub(crate) fn push_text(&mut self, unit: u16) {
+        let text_buffer = match self.text_buffer.as_mut() {
+            Some(text_buffer) => text_buffer,
+            None => return,
+        };
+
+        let units = match self.high_surrogate.take() {
+            Some(high) => vec![high, unit],
+            None if (0xD800..0xDC00).contains(&unit) => {
+                self.high_surrogate = Some(unit);
+                return;
+            }
+            None => vec![unit],
+        };
+
+        text_buffer.extend(
+            char::decode_utf16(units).map(|c| c.unwrap_or(char::REPLACEMENT_CHARACTER)),
+        );
+    }
+
+    pub(crate) fn get_char(&self, char: usize) -> bool {
+        return self.keyboard.is_char(char);
+    }
+
+    pub(crate) fn is_toggled(&self, keycode: usize) -> bool {
+        return self.toggled.get(&keycode).copied().unwrap_or(false);
+    }
+
+    pub(crate) fn get_key(&self, keycode: usize) -> Action {
+        return key_action(&self.keyboard, keycode);
+    }
+
+    pub(crate) fn key_info(&self, keycode: usize) -> KeyInfo {
+        return KeyInfo::from(&self.keyboard, keycode);
+    }
+
+    pub(crate) fn get_key_for(&self, id: WindowId, keycode: usize) -> Action {
+        return match self.keyboards.get(&id) {
+            Some(keyboard) => key_action(keyboard, keycode),
+            None => Action::None,
+        };
+    }
+
+    pub(crate) fn get_mouse_button(&self, button: usize) -> Action {
+        let state = match button {
+            Button::LBUTTON => self.mouse.l_button(),
+            Button::RBUTTON => self.mouse.r_button(),
+            Button::MBUTTON => self.mouse.m_button(),
+            Button::XBUTTON1 => self.mouse.x1_button(),
+            Button::XBUTTON2 => self.mouse.x2_button(),
+            _ => panic!("There is no virtual mouse button code like {button}"),
+        };
+
+        return if state.is_changed() && state.is_down() {
+            Action::Press
+        } else if !state.is_changed() && state.is_down() {
+            Action::Down
+        } else if !state.is_down() && state.is_changed() {
+            Action::Release
+        } else {
+            Action::None
+        };
+    }
+
+    pub(crate) fn snapshot(&self) -> InputSnapshot {
+        let mut keys = Vec::new();
+        for keycode in 0..self.keyboard.n_keys() {
+            let action = self.keyboard.key_state(keycode).action();
+            if action != Action::None {
+                keys.push((keycode, action));
+            }
+        }
+
+        return InputSnapshot {
+            keys,
+            mouse: self.mouse.state(),
+        };
+    }
+
+    pub(crate) fn raw_input(&mut self) -> RawInput {
+        let snapshot = self.snapshot();
+        let text = self
+            .text_buffer
+            .as_mut()
+            .map(std::mem::take)
+            .unwrap_or_default();
+
+        return RawInput {
+            modifiers: Modifiers {
+                control: self.keyboard.is_down(Key::CONTROL),
+                shift: self.keyboard.is_down(Key::SHIFT),
+                alt: self.keyboard.is_down(Key::ALT),
+            },
+            mouse: snapshot.mouse,
+            scroll_delta: std::mem::take(&mut self.scroll_delta),
+            text,
+            keys: snapshot.keys,
+            focused: self.focused,
+        };
+    }
+
+    pub(crate) fn apply_snapshot(&mut self, snapshot: &InputSnapshot) {
+        for keycode in 0..self.keyboard.n_keys() {
+            self.keyboard
+                .set_key_state(keycode, KeyState::from_action(snapshot.get_key(keycode)));
+        }
+
+        self.mouse.set_state(&snapshot.mouse);
+    }
+}
+
+/// Turns one message of a window thread into the public event and updates the input states accordingly. It does not touch any window so it can be fed without a running window thread
+pub(crate) fn translate(state: &mut InputState, main_events: MainEvents) -> Events {
+    state.keyboard.clear();
+    for keyboard in state.keyboards.values_mut() {
+        keyboard.clear();
+    }
+    state.mouse.clear_keystates();
+
+    let events = match main_events {
+        MainEvents::MainWindowEvent { id, event } => match event {
+            MainWindowEvents::Create {
+                size,
+                position,
+                scale_factor,
+            } => Events::WindowEvents {
+                id,
+                event: WindowEvents::Create {
+                    size,
+                    position,
+                    scale_factor,
+                },
+            },
+            MainWindowEvents::CloseRequested => Events::WindowEvents {
+                id,
+                event: WindowEvents::CloseRequested,
+            },
+            MainWindowEvents::Close => {
+                state.lose_focus(id);
+                state.keyboards.remove(&id);
+                Events::WindowEvents {
+                    id,
+                    event: WindowEvents::Close,
+                }
+            }
+            MainWindowEvents::Destroyed => Events::WindowEvents {
+                id,
+                event: WindowEvents::Destroyed,
+            },
+            MainWindowEvents::Maximized { width, height } => Events::WindowEvents {
+                id,
+                event: WindowEvents::Maximized { width, height },
+            },
+            MainWindowEvents::Minimized { width, height } => Events::WindowEvents {
+                id,
+                event: WindowEvents::Minimized { width, height },
+            },
+            MainWindowEvents::FramebufferChanged { width, height } => {
+                Events::WindowEvents {
+                    id,
+                    event: WindowEvents::FramebufferChanged { width, height },
+                }
+            }
+            MainWindowEvents::ResizeStarted => Events::WindowEvents {
+                id,
+                event: WindowEvents::ResizeStarted,
+            },
+            MainWindowEvents::ResizeEnded { width, height } => Events::WindowEvents {
+                id,
+                event: WindowEvents::ResizeEnded { width, height },
+            },
+            MainWindowEvents::Moved { x, y } => Events::WindowEvents {
+                id,
+                event: WindowEvents::Moved { x, y },
+            },
+            MainWindowEvents::SetFocus => {
+                state.focused = Some(id);
+                Events::WindowEvents {
+                    id,
+                    event: WindowEvents::SetFocus,
+                }
+            }
+            MainWindowEvents::LostFocus => {
+                state.lose_focus(id);
+                Events::WindowEvents {
+                    id,
+                    event: WindowEvents::LostFocus,
+                }
+            }
+            MainWindowEvents::RedrawRequested { dirty } => Events::WindowEvents {
+                id,
+                event: WindowEvents::RedrawRequested { dirty },
+            },
+            MainWindowEvents::Timer { id: timer_id } => Events::WindowEvents {
+                id,
+                event: WindowEvents::Timer { id: timer_id },
+            },
+            MainWindowEvents::HoveredFile { path } => Events::WindowEvents {
+                id,
+                event: WindowEvents::HoveredFile { path },
+            },
+            MainWindowEvents::DroppedFile { path } => Events::WindowEvents {
+                id,
+                event: WindowEvents::DroppedFile { path },
+            },
+            MainWindowEvents::HoveredFileCancelled => Events::WindowEvents {
+                id,
+                event: WindowEvents::HoveredFileCancelled,
+            },
+            MainWindowEvents::MenuItem { id: item_id } => Events::WindowEvents {
+                id,
+                event: WindowEvents::MenuItem { id: item_id },
+            },
+            MainWindowEvents::MonitorChanged { monitor } => Events::WindowEvents {
+                id,
+                event: WindowEvents::MonitorChanged { monitor },
+            },
+        },
+        MainEvents::MainKeyboardEvent { id, event } => match event {
+            MainKeyboardEvents::Key {
+                up,
+                is_changed,
+                keycode,
+            } => {
+                let mode = state.key_event_mode;
+                let keyboard = state.keyboards.entry(id).or_insert_with(|| Keyboard::new(false));
+                update_key(keyboard, mode, up, is_changed, keycode);
+
+                match update_key(&mut state.keyboard, mode, up, is_changed, keycode) {
+                    Some(action) => Events::KeyboardEvents {
+                        id,
+                        event: KeyboardEvents::Key { keycode, action },
+                    },
+                    None => Events::None,
+                }
+            }
+
+            MainKeyboardEvents::Char { keycode } => {
+                state.keyboard.set_is_char(keycode, true);
+                if let Some(keyboard) = state.keyboards.get_mut(&id) {
+                    keyboard.set_is_char(keycode, true);
+                }
+                state.push_text(keycode as u16);
+                Events::KeyboardEvents {
+                    id,
+                    event: KeyboardEvents::Char { keycode },
+                }
+            }
+
+            MainKeyboardEvents::Toggle { keycode, on } => {
+                if state.toggled.insert(keycode, on).unwrap_or(false) != on {
+                    Events::KeyboardEvents {
+                        id,
+                        event: KeyboardEvents::ToggleChanged { keycode, on },
+                    }
+                } else {
+                    Events::None
+                }
+            }
+        },
+        MainEvents::MainMouseEvent { id, event } => match event {
+            MainMouseEvents::Scroll {
+                y_offset,
+                modifiers,
+            } => {
+                state.scroll_delta = state.scroll_delta.saturating_add(y_offset as i32);
+
+                Events::MouseEvents {
+                    id,
+                    event: MouseEvents::Scroll {
+                        y_offset,
+                        modifiers,
+                    },
+                }
+            }
+            MainMouseEvents::LButton { up, pos, modifiers } => {
+                if up {
+                    state.mouse.set_l_button_down(false);
+                    state.mouse.set_l_button_released(true);
+                    state.mouse.set_l_button_changed(true);
+
+                    Events::MouseEvents {
+                        id,
+                        event: MouseEvents::LButton {
+                            action: Action::Release,
+                            pos,
+                            modifiers,
+                        },
+                    }
+                } else {
+                    state.mouse.set_l_button_down(true);
+                    state.mouse.set_l_button_released(false);
+                    state.mouse.set_l_button_changed(true);
+
+                    Events::MouseEvents {
+                        id,
+                        event: MouseEvents::LButton {
+                            action: Action::Press,
+                            pos,
+                            modifiers,
+                        },
+                    }
+                }
+            }
+            MainMouseEvents::RButton { up, pos, modifiers } => {
+                if up {
+                    state.mouse.set_r_button_down(false);
+                    state.mouse.set_r_button_released(true);
+                    state.mouse.set_r_button_changed(true);
+
+                    Events::MouseEvents {
+                        id,
+                        event: MouseEvents::RButton {
+                            action: Action::Release,
+                            pos,
+                            modifiers,
+                        },
+                    }
+                } else {
+                    state.mouse.set_r_button_down(true);
+                    state.mouse.set_r_button_released(false);
+                    state.mouse.set_r_button_changed(true);
+
+                    Events::MouseEvents {
+                        id,
+                        event: MouseEvents::RButton {
+                            action: Action::Press,
+                            pos,
+                            modifiers,
+                        },
+                    }
+                }
+            }
+            MainMouseEvents::MButton { up, pos, modifiers } => {
+                if up {
+                    state.mouse.set_m_button_down(false);
+                    state.mouse.set_m_button_released(true);
+                    state.mouse.set_m_button_changed(true);
+
+                    Events::MouseEvents {
+                        id,
+                        event: MouseEvents::MButton {
+                            action: Action::Release,
+                            pos,
+                            modifiers,
+                        },
+                    }
+                } else {
+                    state.mouse.set_m_button_down(true);
+                    state.mouse.set_m_button_released(false);
+                    state.mouse.set_m_button_changed(true);
+
+                    Events::MouseEvents {
+                        id,
+                        event: MouseEvents::MButton {
+                            action: Action::Press,
+                            pos,
+                            modifiers,
+                        },
+                    }
+                }
+            }
+            MainMouseEvents::XButton {
+                up,
+                wparam,
+                pos,
+                modifiers,
+            } => {
+                if up {
+                    if HIWORD(wparam) & XBUTTON1 > 0 {
+                        state.mouse.set_x1_button_down(false);
+                        state.mouse.set_x1_button_released(true);
+                        state.mouse.set_x1_button_changed(true);
+
+                        Events::MouseEvents {
+                            id,
+                            event: MouseEvents::X1Button {
+                                action: Action::Release,
+                                pos,
+                                modifiers,
+                            },
+                        }
+                    } else if HIWORD(wparam) & XBUTTON2 > 0 {
+                        state.mouse.set_x2_button_down(false);
+                        state.mouse.set_x2_button_released(true);
+                        state.mouse.set_x2_button_changed(true);
+
+                        Events::MouseEvents {
+                            id,
+                            event: MouseEvents::X2Button {
+                                action: Action::Release,
+                                pos,
+                                modifiers,
+                            },
+                        }
+                    } else {
+                        Events::None
+                    }
+                } else {
+                    if LOWORD(wparam as u32) as usize & MK_XBUTTON1 > 0 {
+                        state.mouse.set_x1_button_down(true);
+                        state.mouse.set_x1_button_released(false);
+                        state.mouse.set_x1_button_changed(true);
+
+                        Events::MouseEvents {
+                            id,
+                            event: MouseEvents::X1Button {
+                                action: Action::Press,
+                                pos,
+                                modifiers,
+                            },
+                        }
+                    } else if LOWORD(wparam as u32) as usize & MK_XBUTTON2 > 0 {
+                        state.mouse.set_x2_button_down(true);
+                        state.mouse.set_x2_button_released(false);
+                        state.mouse.set_x2_button_changed(true);
+
+                        Events::MouseEvents {
+                            id,
+                            event: MouseEvents::X2Button {
+                                action: Action::Press,
+                                pos,
+                                modifiers,
+                            },
+                        }
+                    } else {
+                        Events::None
+                    }
+                }
+            }
+            MainMouseEvents::MouseMove { x, y } => {
+                state.mouse.update_pos(x, y);
+                Events::MouseEvents {
+                    id,
+                    event: MouseEvents::MouseMove {
+                        x: state.mouse.x(),
+                        y: state.mouse.y(),
+                        last_x: state.mouse.last_x(),
+                        last_y: state.mouse.last_y(),
+                        dx: state.mouse.x_offset(),
+                        dy: state.mouse.y_offset(),
+                    },
+                }
+            }
+            MainMouseEvents::Leave => Events::MouseEvents {
+                id,
+                event: MouseEvents::Leave,
+            },
+        },
+        MainEvents::MainRawMessage {
+            id,
+            msg,
+            wparam,
+            lparam,
+        } => Events::RawMessage {
+            id,
+            msg,
+            wparam,
+            lparam,
+        },
+        MainEvents::MainJobFinished { id } => Events::JobFinished { id },
+        MainEvents::MainError { id, message } => Events::Error { id, message },
+    };
+
+    return match state.mouse_event_mode {
+        MouseEventMode::Specific => events,
+        MouseEventMode::Unified => unify(events),
+    };
+}
+
+fn key_action(keyboard: &Keyboard, keycode: usize) -> Action {
+    return if keyboard.is_down(keycode) && !keyboard.is_changed(keycode) {
+        Action::Down
+    } else if keyboard.is_down(keycode) && keyboard.is_changed(keycode) {
+        Action::Press
+    } else if !keyboard.is_down(keycode) && keyboard.is_changed(keycode) {
+        Action::Release
+    } else {
+        Action::None
+    };
+}
+
+// Returns the action of the event or None if no event should be sent
+fn update_key(
+    keyboard: &mut Keyboard,
+    mode: KeyEventMode,
+    up: bool,
+    is_changed: bool,
+    keycode: usize,
+) -> Option<Action> {
+    if up {
+        keyboard.set_is_down(keycode, false);
+        keyboard.set_is_changed(keycode, true);
+        keyboard.set_is_released(keycode, true);
+
+        return Some(Action::Release);
+    }
+
+    keyboard.set_is_down(keycode, true);
+    keyboard.set_is_changed(keycode, is_changed);
+
+    return if mode == KeyEventMode::Transitions {
+        if is_changed {
+            keyboard.set_is_released(keycode, false);
+            Some(Action::Press)
+        } else {
+            None
+        }
+    } else if !keyboard.is_changed(keycode) {
+        Some(Action::Down)
+    } else if keyboard.is_released(keycode) {
+        keyboard.set_is_released(keycode, false);
+        Some(Action::Press)
+    } else {
+        None
+    };
+}
+
+fn unify(events: Events) -> Events {
+    return match events {
+        Events::MouseEvents { id, event } => {
+            let (button, action, pos, modifiers) = match event {
+                MouseEvents::LButton {
+                    action,
+                    pos,
+                    modifiers,
+                } => (MouseButton::Left, action, pos, modifiers),
+                MouseEvents::RButton {
+                    action,
+                    pos,
+                    modifiers,
+                } => (MouseButton::Right, action, pos, modifiers),
+                MouseEvents::MButton {
+                    action,
+                    pos,
+                    modifiers,
+                } => (MouseButton::Middle, action, pos, modifiers),
+                MouseEvents::X1Button {
+                    action,
+                    pos,
+                    modifiers,
+                } => (MouseButton::X1, action, pos, modifiers),
+                MouseEvents::X2Button {
+                    action,
+                    pos,
+                    modifiers,
+                } => (MouseButton::X2, action, pos, modifiers),
+                _ => return Events::MouseEvents { id, event },
+            };
+
+            Events::MouseEvents {
+                id,
+                event: MouseEvents::Button {
+                    button,
+                    action,
+                    pos,
+                    modifiers,
+                },
+            }
+        }
+        _ => events,
+    };
+}
+
+/// Feeds a scripted sequence of messages through translate() and records every resulting event together with the input states right after it
+#[cfg(test)]
+pub(crate) fn replay<I>(state: &mut InputState, script: I) -> Vec<(Events, InputSnapshot)>
+where
+    I: IntoIterator<Item = MainEvents>,
+{
+    return script
+        .into_iter()
+        .map(|main_events| {
+            let events = translate(state, main_events);
+            return (events, state.snapshot());
+        })
+        .collect();
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn key(id: WindowId, keycode: usize, up: bool, is_changed: bool) -> MainEvents {
+        return MainEvents::MainKeyboardEvent {
+            id,
+            event: MainKeyboardEvents::Key {
+                up,
+                is_changed,
+                keycode,
+            },
+        };
+    }
+
+    fn mouse(id: WindowId, event: MainMouseEvents) -> MainEvents {
+        return MainEvents::MainMouseEvent { id, event };
+    }
+
+    fn key_event(id: WindowId, keycode: usize, action: Action) -> Events {
+        return Events::KeyboardEvents {
+            id,
+            event: KeyboardEvents::Key { keycode, action },
+        };
+    }
+
+    #[test]
+    fn key_down_repeat_up() {
+        let id = WindowId::next();
+        let mut state = InputState::new();
+
+        let frames = replay(
+            &mut state,
+            [
+                key(id, Key::A, false, true),
+                key(id, Key::A, false, false),
+                key(id, Key::A, true, false),
+            ],
+        );
+
+        let events: Vec<Events> = frames.iter().map(|(events, _)| events.clone()).collect();
+        assert_eq!(
+            events,
+            vec![
+                key_event(id, Key::A, Action::Press),
+                key_event(id, Key::A, Action::Down),
+                key_event(id, Key::A, Action::Release),
+            ]
+        );
+
+        let actions: Vec<Action> = frames.iter().map(|(_, input)| input.get_key(Key::A)).collect();
+        assert_eq!(actions, vec![Action::Press, Action::Down, Action::Release]);
+        assert_eq!(state.get_key(Key::A), Action::Release);
+    }
+
+    #[test]
+    fn transitions_skip_repeats() {
+        let id = WindowId::next();
+        let mut state = InputState::new();
+        state.key_event_mode = KeyEventMode::Transitions;
+
+        let frames = replay(
+            &mut state,
+            [
+                key(id, Key::ALT, false, true),
+                key(id, Key::ALT, false, false),
+                key(id, Key::ALT, true, false),
+            ],
+        );
+
+        let events: Vec<Events> = frames.iter().map(|(events, _)| events.clone()).collect();
+        assert_eq!(
+            events,
+            vec![
+                key_event(id, Key::ALT, Action::Press),
+                Events::None,
+                key_event(id, Key::ALT, Action::Release),
+            ]
+        );
+
+        let actions: Vec<Action> =
+            frames.iter().map(|(_, input)| input.get_key(Key::ALT)).collect();
+        assert_eq!(actions, vec![Action::Press, Action::Down, Action::Release]);
+    }
+
+    #[test]
+    fn alt_updates_the_keyboard_of_its_window() {
+        let id = WindowId::next();
+        let other = WindowId::next();
+        let mut state = InputState::new();
+
+        replay(&mut state, [key(id, Key::ALT, false, true)]);
+        assert_eq!(state.get_key_for(id, Key::ALT), Action::Press);
+        assert_eq!(state.get_key_for(other, Key::ALT), Action::None);
+
+        replay(&mut state, [key(id, Key::ALT, true, false)]);
+        assert_eq!(state.get_key_for(id, Key::ALT), Action::Release);
+        assert_eq!(state.get_key(Key::ALT), Action::Release);
+    }
+
+    #[test]
+    fn mouse_moves() {
+        let id = WindowId::next();
+        let mut state = InputState::new();
+
+        let frames = replay(
+            &mut state,
+            [
+                mouse(id, MainMouseEvents::MouseMove { x: 10, y: 20 }),
+                mouse(id, MainMouseEvents::MouseMove { x: 15, y: 18 }),
+            ],
+        );
+
+        assert_eq!(
+            frames[1].0,
+            Events::MouseEvents {
+                id,
+                event: MouseEvents::MouseMove {
+                    x: 15,
+                    y: 18,
+                    last_x: 10,
+                    last_y: 20,
+                    dx: 5,
+                    dy: -2,
+                },
+            }
+        );
+        assert_eq!((frames[1].1.mouse.x, frames[1].1.mouse.y), (15, 18));
+    }
+
+    #[test]
+    fn right_button_does_not_touch_left_button() {
+        let id = WindowId::next();
+        let mut state = InputState::new();
+        let pos = Point::new(4, 2);
+        let modifiers = Modifiers::default();
+
+        let frames = replay(
+            &mut state,
+            [mouse(id, MainMouseEvents::RButton { up: false, pos, modifiers })],
+        );
+        assert_eq!(
+            frames[0].0,
+            Events::MouseEvents {
+                id,
+                event: MouseEvents::RButton {
+                    action: Action::Press,
+                    pos,
+                    modifiers,
+                },
+            }
+        );
+        assert_eq!(state.get_mouse_button(Button::RBUTTON), Action::Press);
+        assert_eq!(state.get_mouse_button(Button::LBUTTON), Action::None);
+
+        let frames = replay(
+            &mut state,
+            [mouse(id, MainMouseEvents::RButton { up: true, pos, modifiers })],
+        );
+        assert_eq!(
+            frames[0].0,
+            Events::MouseEvents {
+                id,
+                event: MouseEvents::RButton {
+                    action: Action::Release,
+                    pos,
+                    modifiers,
+                },
+            }
+        );
+        assert_eq!(state.get_mouse_button(Button::RBUTTON), Action::Release);
+        assert_eq!(state.get_mouse_button(Button::LBUTTON), Action::None);
+    }
+}
@@ -1,11 +1,128 @@
+use std::sync::{
+    atomic::{AtomicU64, Ordering},
+    Arc, Mutex,
+};
+
 use winapi::{
     ctypes::*,
     shared::{minwindef::*, windef::*},
-    um::{dwmapi::DwmSetWindowAttribute, libloaderapi::*, winuser::*},
+    um::{
+        dwmapi::{
+            DwmEnableBlurBehindWindow, DwmExtendFrameIntoClientArea, DwmIsCompositionEnabled,
+            DwmSetWindowAttribute, DWMWA_TRANSITIONS_FORCEDISABLED, DWM_BB_ENABLE, DWM_BLURBEHIND,
+        },
+        libloaderapi::*,
+        processthreadsapi::GetCurrentThreadId,
+        uxtheme::MARGINS,
+        wingdi::DEVMODEW,
+        winuser::*,
+    },
 };
 
 use crate::prelude::*;
 
+/// A stable id of a window. The ids are given in the order the windows are created (starting from 1), so unlike the raw window handles they are the same in every run of the program
+///
+/// # Example
+///
+/// ```ignore
+/// manager.run(|events, control_flow, manager| {
+///     match events {
+///         Events::WindowEvents { id, event: WindowEvents::SetFocus } => println!("Window {id} gained the focus"),
+///         _=> {}
+///     }
+/// });
+/// ```
+#[derive(Default, Debug, Copy, Clone, PartialEq, Eq, Hash, PartialOrd, Ord)]
+pub struct WindowId(pub(crate) u64);
+
+impl WindowId {
+    pub(crate) fn next() -> Self {
+        static NEXT_ID: AtomicU64 = AtomicU64::new(1);
+        return Self(NEXT_ID.fetch_add(1, Ordering::Relaxed));
+    }
+
+    /// Returns the id as a number
+    ///
+    /// # Example
+    ///
+    /// ```ignore
+    /// let id: u64 = manager.window().unwrap().id().as_u64();
+    /// ```
+    pub fn as_u64(&self) -> u64 {
+        return self.0;
+    }
+}
+
+impl std::fmt::Display for WindowId {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}", self.0)
+    }
+}
+
+/// A display which windows are shown on. It is given by WindowEvents::MonitorChanged when a window moves onto another monitor
+///
+/// # Example
+///
+/// ```ignore
+/// manager.run(|events, control_flow, manager| {
+///     match events {
+///         Events::WindowEvents { id: _, event: WindowEvents::MonitorChanged { monitor } } => {
+///             println!("Now running on a {}Hz monitor", monitor.refresh_rate().unwrap_or(60));
+///         }
+///         _=> {}
+///     }
+/// });
+/// ```
+#[derive(Default, Debug, Copy, Clone, PartialEq, Eq, Hash)]
+pub struct MonitorId(pub(crate) usize);
+
+impl MonitorId {
+    pub(crate) unsafe fn from_window(hwnd: HWND) -> Self {
+        return Self(MonitorFromWindow(hwnd, MONITOR_DEFAULTTONEAREST) as usize);
+    }
+
+    /// Returns the raw monitor handle (HMONITOR)
+    ///
+    /// # Example
+    ///
+    /// ```ignore
+    /// let hmonitor = monitor.as_raw() as HMONITOR;
+    /// ```
+    pub fn as_raw(&self) -> usize {
+        return self.0;
+    }
+
+    /// Returns the current refresh rate of the monitor in Hz, or None if it cannot be retrieved (e.g. the monitor has been disconnected)
+    ///
+    /// # Example
+    ///
+    /// ```ignore
+    /// let frame_time = 1.0 / monitor.refresh_rate().unwrap_or(60) as f32;
+    /// ```
+    pub fn refresh_rate(&self) -> Option<u32> {
+        unsafe {
+            let mut info: MONITORINFOEXW = std::mem::zeroed();
+            info.cbSize = std::mem::size_of::<MONITORINFOEXW>() as u32;
+            if GetMonitorInfoW(self.0 as HMONITOR, &mut info as *mut _ as *mut MONITORINFO) == 0 {
+                return None;
+            }
+
+            let mut mode: DEVMODEW = std::mem::zeroed();
+            mode.dmSize = std::mem::size_of::<DEVMODEW>() as u16;
+            if EnumDisplaySettingsW(info.szDevice.as_ptr(), ENUM_CURRENT_SETTINGS, &mut mode) == 0 {
+                return None;
+            }
+
+            // 0 and 1 mean the default refresh rate of the hardware
+            return match mode.dmDisplayFrequency {
+                0 | 1 => None,
+                frequency => Some(frequency),
+            };
+        }
+    }
+}
+
 /// A handle that holds information about a window
 ///
 /// # Example
@@ -14,124 +131,1016 @@ use crate::prelude::*;
 /// let window = Window::default();
 /// assert_eq!(window.get_title(), String::from("Direct Game Engine Window"));
 /// ```
-#[derive(Debug, Clone, PartialEq)]
+#[derive(Debug, Clone)]
 pub struct Window {
+    pub(crate) id: WindowId,
     pub(crate) hwnd: HWND,
     pub(crate) title: String,
     pub(crate) pos: Point,
     pub(crate) size: Size,
+    pub(crate) state: Arc<Mutex<WindowState>>,
+}
+
+impl PartialEq for Window {
+    fn eq(&self, other: &Self) -> bool {
+        return self.id == other.id
+            && self.hwnd == other.hwnd
+            && self.title == other.title
+            && self.pos == other.pos
+            && self.size == other.size;
+    }
 }
 
 impl Default for Window {
     fn default() -> Self {
         return Self {
+            id: WindowId::default(),
             hwnd: std::ptr::null_mut(),
             title: String::from("Direct Game Engine Window"),
             pos: Point::default(),
             size: Size::new(800, 640),
+            state: Arc::default(),
         };
     }
 }
 
-impl Window {
-    /// Creates a new handle with an id
+impl Window {
+    /// Creates a new handle with an id
+    ///
+    /// # Example
+    ///
+    /// ```ignore
+    /// let window = Window::new();
+    /// assert_eq!(window.get_title(), String::from("Direct Game Engine Window"));
+    /// ```
+    pub fn new() -> Self {
+        return Self::default();
+    }
+
+    pub fn with_hwnd(mut self, hwnd: HWND) -> Self {
+        self.hwnd = hwnd;
+        return self;
+    }
+
+    /// Sets the title of that window
+    ///
+    /// # Example
+    ///
+    /// ```ignore
+    /// let mut window = Window::default();
+    /// assert_eq!(window.get_title(), String::from("Direct Game Engine Window"));
+    ///
+    /// window.set_title("Hello, World!");
+    /// assert_eq!(window.get_title(), String::from("Hello, World!"));
+    /// ```
+    pub fn set_title(&mut self, title: &str) {
+        self.title = title.to_owned();
+        let title = Wstring::from(title);
+        unsafe {
+            SetWindowTextW(self.hwnd, title.as_ptr());
+        }
+    }
+
+    /// Sets the icon of the window. (It does not change its icon in properties, only in taskbar and titlebar). A relative path is looked up in the current directory first and then in the directory of the executable. A path with a NUL character is rejected with WindowError::IconPathNul
+    ///
+    /// # Example
+    ///
+    /// ```ignore
+    /// let window = Window::default();
+    /// if let Err(error) = window.set_icon("path\\to\\your\\icon\\.ico") {
+    ///     println!("[ERROR]: {error}");
+    /// }
+    /// ```
+    pub fn set_icon(&self, path: &str) -> Result<(), WindowError> {
+        let icon = load_icon(path)?;
+        unsafe {
+            let old_icon = SetClassLongPtrW(self.hwnd, GCLP_HICON, icon as isize) as HICON;
+            let old_small_icon = SetClassLongPtrW(self.hwnd, GCLP_HICONSM, icon as isize) as HICON;
+            destroy_icons(old_icon, old_small_icon);
+        }
+
+        return Ok(());
+    }
+
+    /// Sets the position of the window
+    ///
+    /// # Example
+    ///
+    /// ```ignore
+    /// let mut window = Window::default();
+    /// assert_eq!(window.get_pos.x, 0); assert_eq!(window.get_pos.y, 0);
+    ///
+    /// window.set_position(Point::new(100, 200));
+    /// assert_eq!(window.get_pos.x, 100); assert_eq!(window.get_pos.y, 200);
+    /// ```
+    pub fn set_pos(&mut self, x: i32, y: i32) {
+        self.pos = Point::new(x, y);
+        unsafe {
+            SetWindowPos(
+                self.hwnd,
+                std::ptr::null_mut(),
+                x,
+                y,
+                0i32,
+                0i32,
+                SWP_DRAWFRAME | SWP_NOZORDER | SWP_NOSIZE | SWP_SHOWWINDOW,
+            );
+        }
+    }
+
+    /// Sets the size of the window
+    ///
+    /// # Example
+    ///
+    /// ```ignore
+    /// let mut window = Window::default();
+    /// assert_eq!(window.get_size().width, 800); assert_eq!(window.get_size().height, 640);
+    ///
+    /// window.set_size(Size::new(1024, 768));
+    /// assert_eq!(window.get_size().width, 1024); assert_eq!(window.get_size().height, 768);
+    /// ```
+    pub fn set_size(&mut self, width: i32, height: i32) {
+        self.size = Size::new(width, height);
+        unsafe {
+            SetWindowPos(
+                self.hwnd,
+                std::ptr::null_mut(),
+                0i32,
+                0i32,
+                width,
+                height,
+                SWP_DRAWFRAME | SWP_NOZORDER | SWP_NOREPOSITION | SWP_SHOWWINDOW,
+            );
+        }
+    }
+
+    /// Sets a callback which can change the sizing information of the window whenever the system asks for it (WM_GETMINMAXINFO). It is more flexible than fixed minimum and maximum sizes, e.g. the window can be maximized into a specific area
+    ///
+    /// # Example
+    ///
+    /// ```ignore
+    /// manager.mut_window().unwrap().set_size_constraints(|info| {
+    ///     info.min_track_size = Size::new(400, 300);
+    ///     info.max_size = Size::new(1280, 720);
+    ///     info.max_position = Point::new(0, 0);
+    /// });
+    /// ```
+    pub fn set_size_constraints<F>(&mut self, callback: F)
+    where
+        F: FnMut(&mut MinMaxInfo) + Send + 'static,
+    {
+        self.state.lock().unwrap().size_constraints = Some(Box::new(callback));
+    }
+
+    /// Sets the minimum and maximum client area sizes and the aspect ratio which are kept while the user resizes the window. They replace the minimum size given to Manager::set_min_size_all() for this window. A callback given to set_size_constraints() can still override the limits
+    ///
+    /// # Example
+    ///
+    /// ```ignore
+    /// manager.window().unwrap().set_resize_constraints(SizeConstraints {
+    ///     min: Some(Size::new(400, 300)),
+    ///     aspect: Some(4.0 / 3.0),
+    ///     ..Default::default()
+    /// });
+    /// ```
+    pub fn set_resize_constraints(&self, constraints: SizeConstraints) {
+        self.state.lock().unwrap().constraints = constraints;
+    }
+
+    /// Sets the smallest client area size the user can resize the window to. None removes the limit
+    ///
+    /// # Example
+    ///
+    /// ```ignore
+    /// manager.window().unwrap().set_min_size(Some(Size::new(320, 240)));
+    /// ```
+    pub fn set_min_size(&self, size: Option<Size>) {
+        self.state.lock().unwrap().constraints.min = size;
+    }
+
+    /// Sets the largest client area size the user can resize (and maximize) the window to. None removes the limit so the window can be maximized to the whole monitor again
+    ///
+    /// # Example
+    ///
+    /// ```ignore
+    /// manager.window().unwrap().set_max_size(Some(Size::new(1920, 1080)));
+    /// ```
+    pub fn set_max_size(&self, size: Option<Size>) {
+        self.state.lock().unwrap().constraints.max = size;
+    }
+
+    /// Keeps the window inside the work area (the monitor without the taskbar) while the user is dragging it so that it cannot be lost behind the taskbar or off a monitor edge
+    ///
+    /// # Example
+    ///
+    /// ```ignore
+    /// manager.mut_window().unwrap().set_confine_to_workarea(true);
+    /// ```
+    pub fn set_confine_to_workarea(&mut self, confine: bool) {
+        self.state.lock().unwrap().confine_to_workarea = confine;
+    }
+
+    /// Starts (or restarts) a timer with the given id which sends WindowEvents::Timer { id } every interval_ms milliseconds
+    ///
+    /// # Example
+    ///
+    /// ```ignore
+    /// manager.window().unwrap().set_timer(1, 5000);
+    ///
+    /// manager.run(|events, control_flow, manager| {
+    ///     match events {
+    ///         Events::WindowEvents { id: _, event: WindowEvents::Timer { id: 1 } } => println!("Autosaving..."),
+    ///         _=> {}
+    ///     }
+    /// });
+    /// ```
+    pub fn set_timer(&self, id: usize, interval_ms: u32) {
+        unsafe {
+            PostMessageW(self.hwnd, WM_DGEWS_SET_TIMER, id, interval_ms as LPARAM);
+        }
+    }
+
+    /// Stops the timer with the given id which has been started with set_timer()
+    ///
+    /// # Example
+    ///
+    /// ```ignore
+    /// manager.window().unwrap().kill_timer(1);
+    /// ```
+    pub fn kill_timer(&self, id: usize) {
+        unsafe {
+            PostMessageW(self.hwnd, WM_DGEWS_KILL_TIMER, id, 0);
+        }
+    }
+
+    /// Sets whether closing the window by the user (the close button, Alt+F4...) only sends WindowEvents::CloseRequested instead of destroying the window, e.g. to ask about the unsaved changes first. It is false by default
+    ///
+    /// # Example
+    ///
+    /// ```ignore
+    /// manager.window().unwrap().set_prevent_close(true);
+    ///
+    /// manager.run(|events, control_flow, manager| {
+    ///     match events {
+    ///         Events::WindowEvents { id: _, event: WindowEvents::CloseRequested } => {
+    ///             if ask_to_quit() {
+    ///                 manager.mut_window().unwrap().destroy();
+    ///             }
+    ///         }
+    ///         _=> {}
+    ///     }
+    /// });
+    /// ```
+    pub fn set_prevent_close(&self, prevent_close: bool) {
+        self.state.lock().unwrap().prevent_close = prevent_close;
+    }
+
+    /// Enables or disables the mouse and keyboard input of the window. A disabled window beeps when it is clicked, which is what the parent of a modal dialog should do while the dialog is open
+    ///
+    /// # Example
+    ///
+    /// ```ignore
+    /// let parent = manager.get_window("Editor").unwrap();
+    /// parent.set_enabled(false);
+    /// // ... the dialog is closed
+    /// parent.set_enabled(true);
+    /// ```
+    pub fn set_enabled(&self, enabled: bool) {
+        self.state.lock().unwrap().disabled = !enabled;
+
+        unsafe {
+            EnableWindow(self.hwnd, enabled as BOOL);
+        }
+    }
+
+    /// Returns whether the window accepts the mouse and keyboard input (see set_enabled())
+    ///
+    /// # Example
+    ///
+    /// ```ignore
+    /// let window = Window::default();
+    /// assert_eq!(window.is_enabled(), true);
+    /// ```
+    pub fn is_enabled(&self) -> bool {
+        return !self.state.lock().unwrap().disabled;
+    }
+
+    /// Asks the window to close as if its close button had been clicked, without touching the other windows. WindowEvents::CloseRequested is sent first and, unless set_prevent_close(true) has been called, the window is destroyed and removed from the Manager with WindowEvents::Destroyed. It returns immediately without waiting for that
+    ///
+    /// # Example
+    ///
+    /// ```ignore
+    /// if let Some(window) = manager.get_window("Inspector") {
+    ///     window.close();
+    /// }
+    /// ```
+    pub fn close(&self) {
+        unsafe {
+            PostMessageW(self.hwnd, WM_CLOSE, 0, 0);
+        }
+    }
+
+    /// Destroys the window. The function waits until the window is destroyed, then WindowEvents::Close and WindowEvents::Destroyed are sent. The window is removed from the Manager only when the latter is processed and its class is unregistered by the thread of the window a little later, so use Manager::destroy_window() to use the class again right away
+    ///
+    /// # Example
+    ///
+    /// ```ignore
+    /// if let Some(window) = manager.get_mut_window("Splash") {
+    ///     window.destroy();
+    /// }
+    /// ```
+    pub fn destroy(&mut self) {
+        unsafe {
+            SendMessageW(self.hwnd, WM_DGEWS_DESTROY, 0, 0);
+        }
+    }
+
+    /// Sets the menu bar of the window. The previous menu is destroyed
+    ///
+    /// # Example
+    ///
+    /// ```ignore
+    /// manager.window().unwrap().set_menu(Menu::new()
+    ///     .with_submenu("&File", Menu::new()
+    ///         .with_item(1, "&Open")
+    ///         .with_item(2, "E&xit")));
+    /// ```
+    pub fn set_menu(&self, menu: Menu) {
+        unsafe {
+            let old_menu = GetMenu(self.hwnd);
+            SetMenu(self.hwnd, menu.build(false));
+            if !old_menu.is_null() {
+                DestroyMenu(old_menu);
+            }
+        }
+    }
+
+    /// Sets the keyboard shortcuts of the window which send WindowEvents::MenuItem { id } like the menu items. The previous shortcuts are removed
+    ///
+    /// # Example
+    ///
+    /// ```ignore
+    /// manager.window().unwrap().set_accelerators(&[
+    ///     Accelerator::new(Key::O, 1).with_control(true),
+    ///     Accelerator::new(Key::F4, 2).with_alt(true),
+    /// ]);
+    /// ```
+    pub fn set_accelerators(&self, accelerators: &[Accelerator]) {
+        unsafe {
+            let table = Accelerator::build_table(accelerators) as usize;
+            let old_table = std::mem::replace(&mut self.state.lock().unwrap().accelerators, table);
+            if old_table != 0 {
+                DestroyAcceleratorTable(old_table as HACCEL);
+            }
+        }
+    }
+
+    /// Returns the client area (the window without its frame and title bar) in the screen coordinates
+    ///
+    /// # Example
+    ///
+    /// ```ignore
+    /// let rect = manager.window().unwrap().client_rect_screen();
+    /// tooltip.set_pos(rect.left + 10, rect.top + 10);
+    /// ```
+    pub fn client_rect_screen(&self) -> Rect {
+        unsafe {
+            let mut rect: RECT = std::mem::zeroed();
+            GetClientRect(self.hwnd, &mut rect);
+
+            let mut left_top = POINT { x: rect.left, y: rect.top };
+            let mut right_bottom = POINT { x: rect.right, y: rect.bottom };
+            ClientToScreen(self.hwnd, &mut left_top);
+            ClientToScreen(self.hwnd, &mut right_bottom);
+
+            return Rect::new(left_top.x, left_top.y, right_bottom.x, right_bottom.y);
+        }
+    }
+
+    /// Sets the drop effect which is shown by the cursor while files are dragged over the window. It can be changed while handling WindowEvents::HoveredFile (e.g. DropEffect::None for unsupported file types) and it is applied from the next mouse move of the drag. DropEffect::Copy is the default one
+    ///
+    /// # Example
+    ///
+    /// ```ignore
+    /// Events::WindowEvents { id, event: WindowEvents::HoveredFile { path } } => {
+    ///     let effect = if path.extension().map_or(false, |ext| ext == "png") { DropEffect::Copy } else { DropEffect::None };
+    ///     manager.get_window_by_id(id).unwrap().set_drop_effect(effect);
+    /// }
+    /// ```
+    pub fn set_drop_effect(&self, effect: DropEffect) {
+        self.state.lock().unwrap().drop_effect = effect;
+    }
+
+    /// Returns the monitor which the largest part of the window is on
+    ///
+    /// # Example
+    ///
+    /// ```ignore
+    /// let monitor = manager.window().unwrap().current_monitor();
+    /// ```
+    pub fn current_monitor(&self) -> MonitorId {
+        return unsafe { MonitorId::from_window(self.hwnd) };
+    }
+
+    /// Returns the work area (the monitor without the taskbar) of the monitor the window is on in the screen coordinates, e.g. to snap the window to the left half of the screen
+    ///
+    /// # Example
+    ///
+    /// ```ignore
+    /// let window = manager.mut_window().unwrap();
+    /// let area = window.work_area();
+    /// window.set_pos(area.left, area.top);
+    /// window.set_size(area.width() / 2, area.height());
+    /// ```
+    pub fn work_area(&self) -> Rect {
+        unsafe {
+            let monitor = MonitorFromWindow(self.hwnd, MONITOR_DEFAULTTONEAREST);
+            let mut info: MONITORINFO = std::mem::zeroed();
+            info.cbSize = std::mem::size_of::<MONITORINFO>() as u32;
+            GetMonitorInfoW(monitor, &mut info);
+
+            let work = info.rcWork;
+            return Rect::new(work.left, work.top, work.right, work.bottom);
+        }
+    }
+
+    /// Moves the window to the center of the work area of the monitor it is on. The whole window with its frame is centered, not only the client area
+    ///
+    /// # Example
+    ///
+    /// ```ignore
+    /// manager.window().unwrap().center();
+    /// ```
+    pub fn center(&self) {
+        unsafe {
+            let mut rect: RECT = std::mem::zeroed();
+            GetWindowRect(self.hwnd, &mut rect);
+
+            let size = Size::new(rect.right - rect.left, rect.bottom - rect.top);
+            let pos = centered(self.work_area(), size);
+            SetWindowPos(
+                self.hwnd,
+                std::ptr::null_mut(),
+                pos.x,
+                pos.y,
+                0,
+                0,
+                SWP_NOSIZE | SWP_NOZORDER | SWP_NOACTIVATE,
+            );
+        }
+    }
+
+    /// Confines the cursor to a rectangle of the client area (e.g. the render region of a game which is smaller than the window). None releases the cursor
+    ///
+    /// # Example
+    ///
+    /// ```ignore
+    /// let window = manager.window().unwrap();
+    /// window.confine_cursor_to_rect(Some(Rect::new(0, 0, 640, 480)));
+    /// // ...
+    /// window.confine_cursor_to_rect(None);
+    /// ```
+    pub fn confine_cursor_to_rect(&self, rect: Option<Rect>) {
+        unsafe {
+            match rect {
+                Some(rect) => {
+                    let mut left_top = POINT { x: rect.left, y: rect.top };
+                    let mut right_bottom = POINT { x: rect.right, y: rect.bottom };
+                    ClientToScreen(self.hwnd, &mut left_top);
+                    ClientToScreen(self.hwnd, &mut right_bottom);
+
+                    let clip = RECT {
+                        left: left_top.x,
+                        top: left_top.y,
+                        right: right_bottom.x,
+                        bottom: right_bottom.y,
+                    };
+                    ClipCursor(&clip);
+                }
+                None => {
+                    ClipCursor(std::ptr::null());
+                }
+            }
+        }
+    }
+
+    /// Returns the placement of the window: whether it is minimized or maximized and its normal rectangle
+    ///
+    /// # Example
+    ///
+    /// ```ignore
+    /// let placement = manager.window().unwrap().get_placement();
+    /// println!("{:?}, normal rect: {}", placement.state, placement.normal_rect);
+    /// ```
+    pub fn get_placement(&self) -> WindowPlacement {
+        unsafe {
+            let mut placement: WINDOWPLACEMENT = std::mem::zeroed();
+            placement.length = std::mem::size_of::<WINDOWPLACEMENT>() as u32;
+            GetWindowPlacement(self.hwnd, &mut placement);
+
+            return WindowPlacement::from(placement);
+        }
+    }
+
+    /// Restores a placement which has been retrieved with get_placement() (e.g. in the last session)
+    ///
+    /// # Example
+    ///
+    /// ```ignore
+    /// manager.window().unwrap().set_placement(saved_placement);
+    /// ```
+    pub fn set_placement(&self, placement: WindowPlacement) {
+        let placement = placement.to_raw();
+        unsafe {
+            SetWindowPlacement(self.hwnd, &placement);
+        }
+    }
+
+    pub(crate) fn set_default_cursor(&self, cursor: Cursor) {
+        let cursor = cursor.load();
+        self.state.lock().unwrap().cursor = cursor as usize;
+
+        unsafe {
+            SetClassLongPtrW(self.hwnd, GCLP_HCURSOR, cursor as isize);
+        }
+    }
+
+    /// Minimizes the window to the taskbar. WindowEvents::Minimized is sent the same way as when the user minimizes it
+    ///
+    /// # Example
+    ///
+    /// ```ignore
+    /// manager.window().unwrap().minimize();
+    /// ```
+    pub fn minimize(&self) {
+        unsafe {
+            ShowWindow(self.hwnd, SW_MINIMIZE);
+        }
+    }
+
+    /// Maximizes the window. WindowEvents::Maximized is sent with the new client size the same way as when the user maximizes it
+    ///
+    /// # Example
+    ///
+    /// ```ignore
+    /// manager.window().unwrap().maximize();
+    ///
+    /// manager.run(|events, control_flow, manager| {
+    ///     match events {
+    ///         Events::WindowEvents { id: _, event: WindowEvents::Maximized { width, height } } => {
+    ///             println!("The window is maximized to {width}x{height}");
+    ///         }
+    ///         _=> {}
+    ///     }
+    /// });
+    /// ```
+    pub fn maximize(&self) {
+        unsafe {
+            ShowWindow(self.hwnd, SW_MAXIMIZE);
+        }
+    }
+
+    /// Restores the minimized or maximized window to its normal size and position. WindowEvents::FramebufferChanged is sent with the restored client size
+    ///
+    /// # Example
+    ///
+    /// ```ignore
+    /// manager.window().unwrap().restore();
+    /// ```
+    pub fn restore(&self) {
+        unsafe {
+            ShowWindow(self.hwnd, SW_RESTORE);
+        }
+    }
+
+    /// Shows the window again after hide() without destroying anything in between
+    ///
+    /// # Example
+    ///
+    /// ```ignore
+    /// if let Some(splash) = manager.get_window("Splash") {
+    ///     splash.show();
+    /// }
+    /// ```
+    pub fn show(&self) {
+        unsafe {
+            ShowWindow(self.hwnd, SW_SHOW);
+        }
+    }
+
+    /// Hides the window. It keeps living and getting the messages, and show() brings it back
+    ///
+    /// # Example
+    ///
+    /// ```ignore
+    /// if let Some(splash) = manager.get_window("Splash") {
+    ///     splash.hide();
+    /// }
+    /// ```
+    pub fn hide(&self) {
+        unsafe {
+            ShowWindow(self.hwnd, SW_HIDE);
+        }
+    }
+
+    /// Returns whether the window is visible. It is asked from the system every time, so it is right even if something else has shown or hidden the window
+    ///
+    /// # Example
+    ///
+    /// ```ignore
+    /// let splash = manager.get_window("Splash").unwrap();
+    /// splash.hide();
+    /// assert_eq!(splash.is_visible(), false);
+    /// ```
+    pub fn is_visible(&self) -> bool {
+        return unsafe { IsWindowVisible(self.hwnd) != 0 };
+    }
+
+    /// Starts moving the window with the mouse as if its title bar had been dragged. Call it while the left mouse button is down, e.g. on MouseEvents::LButton { action: Action::Press, .. } over a custom title bar
+    ///
+    /// # Example
+    ///
+    /// ```ignore
+    /// Events::MouseEvents { id, event: MouseEvents::LButton { action: Action::Press, pos, .. } } => {
+    ///     if pos.y < 32 {
+    ///         manager.get_window_by_id(id).unwrap().drag_window();
+    ///     }
+    /// }
+    /// ```
+    pub fn drag_window(&self) {
+        unsafe {
+            PostMessageW(self.hwnd, WM_DGEWS_DRAG, SC_MOVE | HTCAPTION as usize, 0);
+        }
+    }
+
+    /// Starts resizing the window with the mouse from the given edge or corner as if its border had been dragged. Call it while the left mouse button is down
+    ///
+    /// # Example
+    ///
+    /// ```ignore
+    /// Events::MouseEvents { id, event: MouseEvents::LButton { action: Action::Press, pos, .. } } => {
+    ///     if pos.x < 4 {
+    ///         manager.get_window_by_id(id).unwrap().drag_resize_window(ResizeDirection::West);
+    ///     }
+    /// }
+    /// ```
+    pub fn drag_resize_window(&self, direction: ResizeDirection) {
+        unsafe {
+            PostMessageW(self.hwnd, WM_DGEWS_DRAG, SC_SIZE | direction.wmsz(), 0);
+        }
+    }
+
+    /// Toggles the drop shadow of the window. It is mostly useful for borderless windows which lose their shadow. If the desktop composition is enabled the frame is extended by 1px into the client area, otherwise the class drop shadow is used
+    ///
+    /// # Example
+    ///
+    /// ```ignore
+    /// manager.window().unwrap().set_shadow(true);
+    /// ```
+    pub fn set_shadow(&self, enabled: bool) {
+        unsafe {
+            let mut composition = FALSE;
+            DwmIsCompositionEnabled(&mut composition);
+
+            if composition != FALSE {
+                let margin = if enabled { 1 } else { 0 };
+                let margins = MARGINS {
+                    cxLeftWidth: margin,
+                    cxRightWidth: margin,
+                    cyTopHeight: margin,
+                    cyBottomHeight: margin,
+                };
+
+                DwmExtendFrameIntoClientArea(self.hwnd, &margins);
+            } else {
+                let style = GetClassLongPtrW(self.hwnd, GCL_STYLE) as u32;
+                let style = if enabled { style | CS_DROPSHADOW } else { style & !CS_DROPSHADOW };
+                SetClassLongPtrW(self.hwnd, GCL_STYLE, style as isize);
+            }
+        }
+    }
+
+    /// Extends the client area into the title bar for a custom drawn title bar. The standard frame is removed while the window can still be resized and snapped. The top height pixels of the client area act as the title bar which moves the window. Giving 0 or a negative height brings the standard frame back
+    ///
+    /// # Example
+    ///
+    /// ```ignore
+    /// manager.mut_window().unwrap().set_titlebar_height(32);
+    /// ```
+    pub fn set_titlebar_height(&mut self, height: i32) {
+        let titlebar_height = if height > 0 { Some(height) } else { None };
+        self.state.lock().unwrap().titlebar_height = titlebar_height;
+
+        unsafe {
+            let margin = if titlebar_height.is_some() { 1 } else { 0 };
+            let margins = MARGINS {
+                cxLeftWidth: 0,
+                cxRightWidth: 0,
+                cyTopHeight: margin,
+                cyBottomHeight: 0,
+            };
+            DwmExtendFrameIntoClientArea(self.hwnd, &margins);
+
+            SetWindowPos(
+                self.hwnd,
+                std::ptr::null_mut(),
+                0,
+                0,
+                0,
+                0,
+                SWP_FRAMECHANGED | SWP_NOMOVE | SWP_NOSIZE | SWP_NOZORDER | SWP_NOACTIVATE,
+            );
+        }
+    }
+
+    /// Makes the custom title bar (see set_titlebar_height()) report its maximize button to the system so that Windows 11 shows the snap layouts when the cursor hovers it. The button is expected where the standard one would be: the second caption button (GetSystemMetrics(SM_CXSIZE) wide) from the right edge. Clicking it maximizes or restores the window
+    ///
+    /// # Example
+    ///
+    /// ```ignore
+    /// let window = manager.mut_window().unwrap();
+    /// window.set_titlebar_height(32);
+    /// window.set_snap_layouts_enabled(true);
+    /// ```
+    pub fn set_snap_layouts_enabled(&self, enabled: bool) {
+        self.state.lock().unwrap().snap_layouts = enabled;
+    }
+
+    /// Sets whether double-clicking the title bar maximizes or restores the window. It is enabled by default and it also works for the custom title bar (see set_titlebar_height())
+    ///
+    /// # Example
+    ///
+    /// ```ignore
+    /// manager.window().unwrap().set_double_click_maximize(false);
+    /// ```
+    pub fn set_double_click_maximize(&self, enabled: bool) {
+        self.state.lock().unwrap().no_double_click_maximize = !enabled;
+    }
+
+    /// Shows the system menu (Restore, Move, Size, Minimize, Maximize, Close) at the point of the client area, e.g. when the custom title bar or its icon is right-clicked. The chosen command is carried out by the window
+    ///
+    /// # Example
+    ///
+    /// ```ignore
+    /// Events::MouseEvents { id: _, event: MouseEvents::RButton { action: Action::Release, pos, .. } } => {
+    ///     if pos.y < 32 {
+    ///         manager.window().unwrap().show_system_menu(pos);
+    ///     }
+    /// }
+    /// ```
+    pub fn show_system_menu(&self, pos: Point) {
+        unsafe {
+            // The menu has to be tracked by the thread of the window
+            PostMessageW(
+                self.hwnd,
+                WM_DGEWS_SYSTEM_MENU,
+                0,
+                MAKELONG(pos.x as u16, pos.y as u16) as LPARAM,
+            );
+        }
+    }
+
+    /// Brings the window to the foreground and gives it the keyboard focus. The input of the calling thread is attached for a moment to the threads of the window and of the current foreground window, since the system only lets the thread which owns the focus move it
+    ///
+    /// # Example
+    ///
+    /// ```ignore
+    /// if let Some(window) = manager.get_window("Inspector") {
+    ///     window.focus();
+    /// }
+    /// ```
+    pub fn focus(&self) {
+        unsafe {
+            let current_thread = GetCurrentThreadId();
+            let window_thread = GetWindowThreadProcessId(self.hwnd, std::ptr::null_mut());
+            let foreground_thread =
+                GetWindowThreadProcessId(GetForegroundWindow(), std::ptr::null_mut());
+
+            let mut threads = vec![window_thread];
+            if foreground_thread != window_thread {
+                threads.push(foreground_thread);
+            }
+            threads.retain(|&thread| thread != 0 && thread != current_thread);
+
+            for &thread in &threads {
+                AttachThreadInput(current_thread, thread, TRUE);
+            }
+
+            SetForegroundWindow(self.hwnd);
+            BringWindowToTop(self.hwnd);
+            SetFocus(self.hwnd);
+
+            for &thread in &threads {
+                AttachThreadInput(current_thread, thread, FALSE);
+            }
+        }
+    }
+
+    /// Turns the animations of the system on or off for the window. While they are off the window is shown, hidden, minimized and restored instantly without sliding or fading, which keeps the tooltips and the overlays responsive
+    ///
+    /// # Example
+    ///
+    /// ```ignore
+    /// manager.window().unwrap().set_animations_enabled(false);
+    /// ```
+    pub fn set_animations_enabled(&self, enabled: bool) {
+        unsafe {
+            Self::animations(self.hwnd, enabled);
+        }
+    }
+
+    /// Flashes the window to get the attention of the user. UserAttentionType::Critical flashes both the title bar and the taskbar button until the window gets the focus, UserAttentionType::Informational flashes only the taskbar button a few times. None stops the flashing
+    ///
+    /// # Example
+    ///
+    /// ```ignore
+    /// manager.window().unwrap().request_user_attention(Some(UserAttentionType::Informational));
+    /// ```
+    pub fn request_user_attention(&self, attention: Option<UserAttentionType>) {
+        let (flags, count) = match attention {
+            Some(UserAttentionType::Critical) => (FLASHW_ALL | FLASHW_TIMERNOFG, 0u32),
+            Some(UserAttentionType::Informational) => (FLASHW_TRAY, 3u32),
+            None => (FLASHW_STOP, 0u32),
+        };
+
+        let mut info = FLASHWINFO {
+            cbSize: std::mem::size_of::<FLASHWINFO>() as u32,
+            hwnd: self.hwnd,
+            dwFlags: flags,
+            uCount: count,
+            dwTimeout: 0u32,
+        };
+
+        unsafe {
+            FlashWindowEx(&mut info);
+        }
+    }
+
+    /// Toggles the blur behind the window (a frosted glass look). Only the black parts of the client area are see-through, so clear it with black where the blur should show
     ///
     /// # Example
     ///
     /// ```ignore
-    /// let window = Window::new();
-    /// assert_eq!(window.get_title(), String::from("Direct Game Engine Window"));
+    /// manager.window().unwrap().enable_blur_behind(true);
     /// ```
-    pub fn new() -> Self {
-        return Self::default();
+    pub fn enable_blur_behind(&self, enabled: bool) {
+        unsafe {
+            Self::blur_behind(self.hwnd, enabled);
+        }
     }
 
-    pub fn with_hwnd(mut self, hwnd: HWND) -> Self {
-        self.hwnd = hwnd;
-        return self;
+    pub(crate) unsafe fn animations(hwnd: HWND, enabled: bool) {
+        let disabled: BOOL = if enabled { FALSE } else { TRUE };
+        DwmSetWindowAttribute(
+            hwnd,
+            DWMWA_TRANSITIONS_FORCEDISABLED,
+            &disabled as *const BOOL as *const c_void,
+            std::mem::size_of_val(&disabled) as DWORD,
+        );
     }
 
-    /// Sets the title of that window
+    pub(crate) unsafe fn blur_behind(hwnd: HWND, enabled: bool) {
+        // The whole client area is blurred when there is no region
+        let blur = DWM_BLURBEHIND {
+            dwFlags: DWM_BB_ENABLE,
+            fEnable: if enabled { TRUE } else { FALSE },
+            hRgnBlur: std::ptr::null_mut(),
+            fTransitionOnMaximized: FALSE,
+        };
+
+        DwmEnableBlurBehindWindow(hwnd, &blur);
+    }
+
+    /// Keeps the window below all the other windows like a desktop widget (e.g. a wallpaper clock). The window is not activated when it is clicked while it is on the bottom
     ///
     /// # Example
     ///
     /// ```ignore
-    /// let mut window = Window::default();
-    /// assert_eq!(window.get_title(), String::from("Direct Game Engine Window"));
-    ///
-    /// window.set_title("Hello, World!");
-    /// assert_eq!(window.get_title(), String::from("Hello, World!"));
+    /// manager.window().unwrap().set_always_on_bottom(true);
     /// ```
-    pub fn set_title(&mut self, title: &str) {
-        self.title = title.to_owned();
+    pub fn set_always_on_bottom(&self, enabled: bool) {
+        self.state.lock().unwrap().always_on_bottom = enabled;
+
         unsafe {
-            SetWindowTextW(self.hwnd, wchar(title));
+            let ex_style = GetWindowLongPtrW(self.hwnd, GWL_EXSTYLE) as u32;
+            let ex_style = if enabled {
+                ex_style | WS_EX_NOACTIVATE
+            } else {
+                ex_style & !WS_EX_NOACTIVATE
+            };
+            SetWindowLongPtrW(self.hwnd, GWL_EXSTYLE, ex_style as isize);
+
+            let insert_after = if enabled { HWND_BOTTOM } else { HWND_TOP };
+            SetWindowPos(
+                self.hwnd,
+                insert_after,
+                0,
+                0,
+                0,
+                0,
+                SWP_NOMOVE | SWP_NOSIZE | SWP_NOACTIVATE,
+            );
         }
     }
 
-    /// Sets the icon of the window. (It does not change its icon in properties, only in taskbar and titlebar)
+    /// Limits the mouse input of the window to a rect of the client area, e.g. the widgets of a mostly transparent overlay. The clicks outside of it pass through the window (the system gives them only to the windows below which belong to the same thread). None restores the input of the whole window
     ///
     /// # Example
     ///
     /// ```ignore
-    /// let window = Window::default();
-    /// window.set_icon("path\\to\\your\\icon\\.ico");
+    /// manager.window().unwrap().set_input_region(Some(Rect::new(10, 10, 210, 60)));
     /// ```
-    pub fn set_icon(&self, path: &str) {
+    pub fn set_input_region(&self, region: Option<Rect>) {
+        self.state.lock().unwrap().input_region = region;
+    }
+
+    /// Keeps the window above all the non-topmost windows, even when it is not active (e.g. an overlay tool). It turns set_always_on_bottom() off
+    ///
+    /// # Example
+    ///
+    /// ```ignore
+    /// manager.window().unwrap().set_always_on_top(true);
+    /// ```
+    pub fn set_always_on_top(&self, enabled: bool) {
+        if enabled && self.state.lock().unwrap().always_on_bottom {
+            self.set_always_on_bottom(false);
+        }
+
         unsafe {
-            SetClassLongPtrW(self.hwnd, GCLP_HICON, load_icon(path) as isize);
+            Self::topmost(self.hwnd, enabled);
         }
     }
 
-    /// Sets the position of the window
+    /// Returns whether the window is kept above the other windows. It is asked from the system every time
     ///
     /// # Example
     ///
     /// ```ignore
-    /// let mut window = Window::default();
-    /// assert_eq!(window.get_pos.x, 0); assert_eq!(window.get_pos.y, 0);
+    /// let window = manager.window().unwrap();
+    /// window.set_always_on_top(true);
+    /// assert_eq!(window.is_always_on_top(), true);
+    /// ```
+    pub fn is_always_on_top(&self) -> bool {
+        return unsafe { GetWindowLongW(self.hwnd, GWL_EXSTYLE) as u32 & WS_EX_TOPMOST != 0 };
+    }
+
+    pub(crate) unsafe fn topmost(hwnd: HWND, enabled: bool) {
+        let insert_after = if enabled { HWND_TOPMOST } else { HWND_NOTOPMOST };
+        SetWindowPos(
+            hwnd,
+            insert_after,
+            0,
+            0,
+            0,
+            0,
+            SWP_NOMOVE | SWP_NOSIZE | SWP_NOACTIVATE,
+        );
+    }
+
+    /// Sets the opacity of the whole window from 0.0 (invisible) to 1.0 (opaque), e.g. to fade it in or out. The value is clamped to that range. The window becomes a layered window while it is translucent and stops being one at 1.0
     ///
-    /// window.set_position(Point::new(100, 200));
-    /// assert_eq!(window.get_pos.x, 100); assert_eq!(window.get_pos.y, 200);
+    /// # Example
+    ///
+    /// ```ignore
+    /// let alpha = ease::ease_in_out((timer.time() - start) / 0.3);
+    /// manager.window().unwrap().set_opacity(alpha);
     /// ```
-    pub fn set_pos(&mut self, x: i32, y: i32) {
-        self.pos = Point::new(x, y);
+    pub fn set_opacity(&self, alpha: f32) {
         unsafe {
-            SetWindowPos(
-                self.hwnd,
-                std::ptr::null_mut(),
-                x,
-                y,
-                0i32,
-                0i32,
-                SWP_DRAWFRAME | SWP_NOZORDER | SWP_NOSIZE | SWP_SHOWWINDOW,
-            );
+            Self::opacity(self.hwnd, alpha);
         }
     }
 
-    /// Sets the size of the window
+    pub(crate) unsafe fn opacity(hwnd: HWND, alpha: f32) {
+        let alpha = alpha.clamp(0.0f32, 1.0f32);
+        let ex_style = GetWindowLongW(hwnd, GWL_EXSTYLE) as u32;
+
+        if alpha >= 1.0f32 {
+            // An opaque layered window is still composed slower (and breaks the flip model swap chains)
+            if ex_style & WS_EX_LAYERED != 0 {
+                SetWindowLongW(hwnd, GWL_EXSTYLE, (ex_style & !WS_EX_LAYERED) as i32);
+            }
+            return;
+        }
+
+        if ex_style & WS_EX_LAYERED == 0 {
+            SetWindowLongW(hwnd, GWL_EXSTYLE, (ex_style | WS_EX_LAYERED) as i32);
+        }
+        SetLayeredWindowAttributes(hwnd, 0, (alpha * 255.0f32).round() as u8, LWA_ALPHA);
+    }
+
+    /// Tells the IME where the text field is (in the client coordinates) so that the composition is shown inside it and the candidate list does not cover it
     ///
     /// # Example
     ///
     /// ```ignore
-    /// let mut window = Window::default();
-    /// assert_eq!(window.get_size().width, 800); assert_eq!(window.get_size().height, 640);
-    ///
-    /// window.set_size(Size::new(1024, 768));
-    /// assert_eq!(window.get_size().width, 1024); assert_eq!(window.get_size().height, 768);
+    /// manager.window().unwrap().set_text_input_area(Rect::from_pos_size(Point::new(20, 40), Size::new(300, 24)));
     /// ```
-    pub fn set_size(&mut self, width: i32, height: i32) {
-        self.size = Size::new(width, height);
+    pub fn set_text_input_area(&self, area: Rect) {
+        self.state.lock().unwrap().text_input_area = Some(area);
+
         unsafe {
-            SetWindowPos(
-                self.hwnd,
-                std::ptr::null_mut(),
-                0i32,
-                0i32,
-                width,
-                height,
-                SWP_DRAWFRAME | SWP_NOZORDER | SWP_NOREPOSITION | SWP_SHOWWINDOW,
-            );
+            PostMessageW(self.hwnd, WM_DGEWS_TEXT_INPUT_AREA, 0, 0);
         }
     }
 
@@ -141,10 +1150,35 @@ impl Window {
     ///
     /// ```ignore
     /// let window = Window::default();
-    /// assert_eq!(window.get_id(), 0); // The id of the window is 0 by default.
+    /// assert_eq!(window.get_id().as_u64(), 0); // The id of the window is 0 by default.
+    /// ```
+    pub fn get_id(&self) -> WindowId {
+        return self.id;
+    }
+
+    /// Returns the id of the window (the same as get_id())
+    ///
+    /// # Example
+    ///
+    /// ```ignore
+    /// if manager.window().unwrap().id() == id {
+    ///     println!("The event is from the default window");
+    /// }
+    /// ```
+    pub fn id(&self) -> WindowId {
+        return self.id;
+    }
+
+    /// Returns the raw window handle (HWND) for the interop with the win32 api
+    ///
+    /// # Example
+    ///
+    /// ```ignore
+    /// let hwnd = manager.window().unwrap().raw_hwnd();
+    /// unsafe { winapi::um::winuser::FlashWindow(hwnd, 1) };
     /// ```
-    pub fn get_id(&self) -> usize {
-        return self.hwnd as usize;
+    pub fn raw_hwnd(&self) -> HWND {
+        return self.hwnd;
     }
 
     /// Returns the title of the window
@@ -159,9 +1193,25 @@ impl Window {
         return self.title.clone();
     }
 
-    #[allow(dead_code)]
-    pub(crate) fn title(&self) -> *const u16 {
-        return wchar(&self.title);
+    /// Reads the title back from the window itself instead of the cached copy which get_title() returns
+    ///
+    /// # Example
+    ///
+    /// ```ignore
+    /// window.set_title("日本語 🎮");
+    /// assert_eq!(window.query_title(), String::from("日本語 🎮"));
+    /// ```
+    pub fn query_title(&self) -> String {
+        unsafe {
+            let length = GetWindowTextLengthW(self.hwnd);
+            if length <= 0 {
+                return String::new();
+            }
+
+            let mut buffer = vec![0u16; length as usize + 1usize];
+            let copied = GetWindowTextW(self.hwnd, buffer.as_mut_ptr(), buffer.len() as i32);
+            return String::from_utf16_lossy(&buffer[..copied.max(0) as usize]);
+        }
     }
 
     /// Returns the position of the window
@@ -258,10 +1308,12 @@ impl Window {
         };
 
         return Self {
+            id: WindowId::default(),
             hwnd,
             title,
             pos: Point::new(x, y),
             size: Size::new(w, h),
+            state: Arc::default(),
         };
     }
 
@@ -282,7 +1334,7 @@ impl Window {
         }
     }
 
-    /// Sends redrawing request message to the manager
+    /// Sends redrawing request message to the manager. The window is repainted as soon as its thread has no other message to handle, so the calls which come before that are merged into one WindowEvents::RedrawRequested event for the whole client area
     /// 
     /// # Example
     /// 
@@ -290,7 +1342,7 @@ impl Window {
     /// manager.run(|events, control_flow, manager| {
     ///     match events {
     ///         WindowEvents { id, event } => match event {
-    ///             WindowEvents::RedrawRequested => { .. },
+    ///             WindowEvents::RedrawRequested { .. } => { .. },
     /// 
     ///             _=> {}
     ///         }
@@ -306,6 +1358,34 @@ impl Window {
         }
     }
 
+    /// Sends redrawing request message for only a part of the client area. The rects of the requests which arrive before the window is repainted are merged into the dirty rect of one WindowEvents::RedrawRequested event
+    ///
+    /// # Example
+    ///
+    /// ```ignore
+    /// let line = Rect::new(0, row * line_height, width, (row + 1) * line_height);
+    /// manager.window().request_redraw_rect(line);
+    ///
+    /// manager.run(|events, control_flow, manager| {
+    ///     match events {
+    ///         Events::WindowEvents { id: _, event: WindowEvents::RedrawRequested { dirty } } => repaint(dirty),
+    ///         _=> {}
+    ///     }
+    /// });
+    /// ```
+    pub fn request_redraw_rect(&self, rect: Rect) {
+        let rect = RECT {
+            left: rect.left,
+            top: rect.top,
+            right: rect.right,
+            bottom: rect.bottom,
+        };
+
+        unsafe {
+            InvalidateRect(self.hwnd, &rect, FALSE);
+        }
+    }
+
     pub fn get_hwnd_class_name(hwnd: HWND) -> String {
         unsafe {
             let mut class = Vec::with_capacity(512);
@@ -315,12 +1395,41 @@ impl Window {
         }
     }
 
+    // Fails silently if there is still another window with the same class, then its icons are still in use
+    pub(crate) unsafe fn unregister(class: &str) {
+        let class = Wstring::from(class);
+        let instance = GetModuleHandleW(std::ptr::null_mut());
+
+        let mut wc: WNDCLASSEXW = std::mem::zeroed();
+        wc.cbSize = std::mem::size_of::<WNDCLASSEXW>() as u32;
+        let found = GetClassInfoExW(instance, class.as_ptr(), &mut wc) != 0;
+
+        if UnregisterClassW(class.as_ptr(), instance) != 0 && found {
+            destroy_icons(wc.hIcon, wc.hIconSm);
+        }
+    }
+
     pub(crate) unsafe fn register<T>(
         class: &str,
         builder: WindowBuilder,
         data: *const T,
         callback: unsafe extern "system" fn(HWND, UINT, WPARAM, LPARAM) -> LRESULT,
-    ) -> HWND {
+    ) -> Result<HWND, WindowError> {
+        // A degenerate size would create a window which is never seen, so it is clamped. The rest has to be valid
+        let builder = builder.clamped();
+        builder.validate()?;
+
+        // No icon means the default one
+        let icon = if builder.icon.is_empty() {
+            std::ptr::null_mut()
+        } else {
+            load_icon(&builder.icon)?
+        };
+
+        // Both have to outlive the calls which take their pointers
+        let class_name = Wstring::from(class);
+        let title = builder.title();
+
         let wc = WNDCLASSEXW {
             cbSize: std::mem::size_of::<WNDCLASSEXW>() as u32,
             style: 0u32,
@@ -328,34 +1437,71 @@ impl Window {
             cbClsExtra: 0i32,
             cbWndExtra: 0i32,
             hInstance: GetModuleHandleW(std::ptr::null_mut()),
-            hIcon: load_icon(&builder.icon).cast(),
-            hCursor: std::ptr::null_mut(),
+            hIcon: icon,
+            hCursor: Cursor::Arrow.load(),
             hbrBackground: std::ptr::null_mut(),
             lpszMenuName: std::ptr::null_mut(),
-            lpszClassName: wchar(class),
-            hIconSm: load_icon(&builder.icon).cast(),
+            lpszClassName: class_name.as_ptr(),
+            hIconSm: icon,
         };
 
-        RegisterClassExW(&wc);
+        if RegisterClassExW(&wc) == 0 {
+            // e.g. the class name is already used by another window
+            let code = winapi::um::errhandlingapi::GetLastError();
+            destroy_icons(icon, icon);
+            return Err(WindowError::ClassRegistration {
+                class: class.to_owned(),
+                code,
+            });
+        }
+
+        // A centered window is put onto the primary monitor which contains the origin
+        let size = if builder.logical_size {
+            let target = if builder.centered { Point::default() } else { builder.pos };
+            Size::to_physical(builder.size, scale_factor_at(target))
+        } else {
+            builder.size
+        };
 
         let mut wr: RECT = std::mem::zeroed();
         wr.left = 100i32;
         wr.top = 100i32;
-        wr.right = wr.left + builder.get_width();
-        wr.bottom = wr.top + builder.get_height();
+        wr.right = wr.left + size.width;
+        wr.bottom = wr.top + size.height;
 
-        let mut style = WS_OVERLAPPED | WS_CAPTION | WS_SYSMENU | WS_MINIMIZEBOX | WS_MAXIMIZEBOX;
+        let mut style = if builder.decorations {
+            WS_OVERLAPPED | WS_CAPTION | WS_SYSMENU | WS_MINIMIZEBOX | WS_MAXIMIZEBOX
+        } else {
+            // The minimize box lets the taskbar button still minimize the borderless window
+            WS_POPUP | WS_MINIMIZEBOX
+        };
         if builder.resizable {
             style |= WS_THICKFRAME;
         }
+        let mut ex_style = 0u32;
+        if builder.no_activate {
+            ex_style |= WS_EX_NOACTIVATE;
+        }
+
         AdjustWindowRect(&mut wr, style, FALSE);
+
+        let pos = if builder.centered {
+            let mut work: RECT = std::mem::zeroed();
+            SystemParametersInfoW(SPI_GETWORKAREA, 0, &mut work as *mut RECT as *mut c_void, 0);
+
+            let work = Rect::new(work.left, work.top, work.right, work.bottom);
+            centered(work, Size::new(wr.right - wr.left, wr.bottom - wr.top))
+        } else {
+            builder.pos
+        };
+
         let hwnd = CreateWindowExW(
-            0u32,
-            wchar(class),
-            wchar(&builder.title),
+            ex_style,
+            class_name.as_ptr(),
+            title.as_ptr(),
             style,
-            builder.get_x(),
-            builder.get_y(),
+            pos.x,
+            pos.y,
             wr.right - wr.left,
             wr.bottom - wr.top,
             std::ptr::null_mut(),
@@ -364,6 +1510,15 @@ impl Window {
             data as *mut c_void,
         );
 
+        if hwnd.is_null() {
+            let code = winapi::um::errhandlingapi::GetLastError();
+            Self::unregister(class);
+            return Err(WindowError::Creation {
+                class: class.to_owned(),
+                code,
+            });
+        }
+
         let (darkmode, value) = match builder.get_theme() {
             Theme::Dark => (20, TRUE),
             Theme::Light => (0, FALSE),
@@ -376,14 +1531,313 @@ impl Window {
             std::mem::size_of_val(&value) as DWORD,
         );
 
-        ShowWindow(hwnd, SW_SHOW);
+        if builder.blur {
+            Self::blur_behind(hwnd, true);
+        }
+
+        if builder.no_animations {
+            Self::animations(hwnd, false);
+        }
+
+        if builder.always_on_top {
+            Self::topmost(hwnd, true);
+        }
+
+        if builder.opacity < 1.0f32 {
+            Self::opacity(hwnd, builder.opacity);
+        }
+
+        // WM_SIZE sends the first Maximized or Minimized event like for any later change
+        let show = if builder.maximized {
+            SW_SHOWMAXIMIZED
+        } else if builder.minimized {
+            if builder.no_activate { SW_SHOWMINNOACTIVE } else { SW_SHOWMINIMIZED }
+        } else if builder.no_activate {
+            SW_SHOWNOACTIVATE
+        } else {
+            SW_SHOW
+        };
+        ShowWindow(hwnd, show);
+
+        return Ok(hwnd);
+    }
+}
+
+// The window may be larger than the area, then it overhangs it evenly on both sides
+fn centered(area: Rect, size: Size) -> Point {
+    return Point::new(
+        area.left + (area.width() - size.width) / 2,
+        area.top + (area.height() - size.height) / 2,
+    );
+}
+
+/// The importance of the attention request of Window::request_user_attention()
+///
+/// # Example
+///
+/// ```ignore
+/// window.request_user_attention(Some(UserAttentionType::Critical));
+/// ```
+#[derive(Default, Debug, Copy, Clone, PartialEq, Eq)]
+pub enum UserAttentionType {
+    /// Flashes the title bar and the taskbar button until the window gets the focus
+    Critical,
+    /// Flashes the taskbar button a few times
+    #[default]
+    Informational,
+}
+
+/// What happens to the files which are dropped on a window. The cursor of the drag shows it
+///
+/// # Example
+///
+/// ```ignore
+/// window.set_drop_effect(DropEffect::Move);
+/// ```
+#[derive(Default, Debug, Copy, Clone, PartialEq, Eq, Hash)]
+pub enum DropEffect {
+    /// The files cannot be dropped
+    None,
+    /// The files are copied
+    #[default]
+    Copy,
+    /// The files are moved
+    Move,
+    /// The files are linked
+    Link,
+}
+
+/// The edge or the corner of Window::drag_resize_window()
+///
+/// # Example
+///
+/// ```ignore
+/// window.drag_resize_window(ResizeDirection::SouthEast);
+/// ```
+#[derive(Debug, Copy, Clone, PartialEq, Eq, Hash)]
+pub enum ResizeDirection {
+    /// The top edge
+    North,
+    /// The bottom edge
+    South,
+    /// The right edge
+    East,
+    /// The left edge
+    West,
+    /// The top-right corner
+    NorthEast,
+    /// The top-left corner
+    NorthWest,
+    /// The bottom-right corner
+    SouthEast,
+    /// The bottom-left corner
+    SouthWest,
+}
+
+impl ResizeDirection {
+    pub(crate) fn wmsz(&self) -> usize {
+        return match self {
+            ResizeDirection::North => WMSZ_TOP,
+            ResizeDirection::South => WMSZ_BOTTOM,
+            ResizeDirection::East => WMSZ_RIGHT,
+            ResizeDirection::West => WMSZ_LEFT,
+            ResizeDirection::NorthEast => WMSZ_TOPRIGHT,
+            ResizeDirection::NorthWest => WMSZ_TOPLEFT,
+            ResizeDirection::SouthEast => WMSZ_BOTTOMRIGHT,
+            ResizeDirection::SouthWest => WMSZ_BOTTOMLEFT,
+        } as usize;
+    }
+}
+
+/// Sizing information of a window. It mirrors the MINMAXINFO struct of the win32 api and is given to the callback of Window::set_size_constraints()
+///
+/// # Example
+///
+/// ```ignore
+/// window.set_size_constraints(|info: &mut MinMaxInfo| {
+///     info.min_track_size = Size::new(400, 300);
+/// });
+/// ```
+#[derive(Default, Debug, Copy, Clone, PartialEq)]
+pub struct MinMaxInfo {
+    /// Size of the window when it is maximized
+    pub max_size: Size,
+    /// Position of the left-top corner of the window when it is maximized
+    pub max_position: Point,
+    /// Minimum size of the window when it is resized by the user
+    pub min_track_size: Size,
+    /// Maximum size of the window when it is resized by the user
+    pub max_track_size: Size,
+}
+
+impl MinMaxInfo {
+    pub(crate) fn from(mmi: MINMAXINFO) -> Self {
+        return Self {
+            max_size: Size::new(mmi.ptMaxSize.x, mmi.ptMaxSize.y),
+            max_position: Point::new(mmi.ptMaxPosition.x, mmi.ptMaxPosition.y),
+            min_track_size: Size::new(mmi.ptMinTrackSize.x, mmi.ptMinTrackSize.y),
+            max_track_size: Size::new(mmi.ptMaxTrackSize.x, mmi.ptMaxTrackSize.y),
+        };
+    }
+
+    pub(crate) fn apply(&self, mmi: &mut MINMAXINFO) {
+        mmi.ptMaxSize = POINT { x: self.max_size.width, y: self.max_size.height };
+        mmi.ptMaxPosition = POINT { x: self.max_position.x, y: self.max_position.y };
+        mmi.ptMinTrackSize = POINT { x: self.min_track_size.width, y: self.min_track_size.height };
+        mmi.ptMaxTrackSize = POINT { x: self.max_track_size.width, y: self.max_track_size.height };
+    }
+}
+
+/// Limits of the client area size of a window which are enforced together while the user resizes it. When they conflict the minimum size wins over the maximum size and both of them win over the aspect ratio, so the window does not jitter between them
+///
+/// # Example
+///
+/// ```ignore
+/// manager.window().unwrap().set_resize_constraints(SizeConstraints {
+///     min: Some(Size::new(320, 180)),
+///     max: Some(Size::new(1920, 1080)),
+///     aspect: Some(16.0 / 9.0),
+/// });
+/// ```
+#[derive(Default, Debug, Copy, Clone, PartialEq)]
+pub struct SizeConstraints {
+    /// Minimum size of the client area
+    pub min: Option<Size>,
+    /// Maximum size of the client area
+    pub max: Option<Size>,
+    /// Width divided by height of the client area
+    pub aspect: Option<f32>,
+}
+
+impl SizeConstraints {
+    pub(crate) fn is_empty(&self) -> bool {
+        return self.min.is_none() && self.max.is_none() && self.aspect.is_none();
+    }
+
+    // keep_width tells which side the user is dragging, the other one follows the aspect ratio
+    pub(crate) fn apply(&self, size: Size, keep_width: bool) -> Size {
+        let mut size = size;
+        if let Some(max) = self.max {
+            size = size.min(max);
+        }
+
+        if let Some(aspect) = self.aspect.filter(|aspect| *aspect > 0.0f32) {
+            let aspect = aspect as f64;
+            if keep_width {
+                size.height = scale(size.width, 1.0 / aspect);
+            } else {
+                size.width = scale(size.height, aspect);
+            }
+
+            // The aspect ratio is kept inside the maximum size when it is possible
+            if let Some(max) = self.max {
+                if size.width > max.width {
+                    size = Size::new(max.width, scale(max.width, 1.0 / aspect));
+                }
+                if size.height > max.height {
+                    size = Size::new(scale(max.height, aspect), max.height);
+                }
+            }
+        }
+
+        if let Some(min) = self.min {
+            size = size.max(min);
+        }
+
+        return size;
+    }
+}
+
+/// The state of a window in WindowPlacement
+///
+/// # Example
+///
+/// ```ignore
+/// if window.get_placement().state == PlacementState::Maximized {
+///     println!("The window is maximized");
+/// }
+/// ```
+#[derive(Default, Debug, Copy, Clone, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum PlacementState {
+    /// The window is neither minimized nor maximized
+    #[default]
+    Normal,
+    /// The window is minimized
+    Minimized,
+    /// The window is maximized
+    Maximized,
+}
+
+/// The placement of a window: its state and the rectangle it has when it is restored. Unlike a plain position and size it remembers the normal size of a maximized window, so it can be saved and restored across the sessions
+///
+/// # Example
+///
+/// ```ignore
+/// let placement = manager.window().unwrap().get_placement();
+/// // ... save it and in the next session:
+/// manager.window().unwrap().set_placement(placement);
+/// ```
+#[derive(Default, Debug, Copy, Clone, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct WindowPlacement {
+    /// Whether the window is normal, minimized or maximized
+    pub state: PlacementState,
+    /// Whether a minimized window is maximized when it is restored
+    pub restore_to_maximized: bool,
+    /// Position of the left-top corner of the window when it is minimized
+    pub min_position: Point,
+    /// Position of the left-top corner of the window when it is maximized
+    pub max_position: Point,
+    /// Rectangle of the window when it is neither minimized nor maximized (in the work area coordinates)
+    pub normal_rect: Rect,
+}
+
+impl WindowPlacement {
+    pub(crate) fn from(placement: WINDOWPLACEMENT) -> Self {
+        let state = match placement.showCmd as i32 {
+            SW_SHOWMINIMIZED | SW_MINIMIZE | SW_SHOWMINNOACTIVE => PlacementState::Minimized,
+            SW_SHOWMAXIMIZED => PlacementState::Maximized,
+            _ => PlacementState::Normal,
+        };
+        let rect = placement.rcNormalPosition;
+
+        return Self {
+            state,
+            restore_to_maximized: placement.flags & WPF_RESTORETOMAXIMIZED != 0,
+            min_position: Point::new(placement.ptMinPosition.x, placement.ptMinPosition.y),
+            max_position: Point::new(placement.ptMaxPosition.x, placement.ptMaxPosition.y),
+            normal_rect: Rect::new(rect.left, rect.top, rect.right, rect.bottom),
+        };
+    }
+
+    pub(crate) fn to_raw(self) -> WINDOWPLACEMENT {
+        let show_cmd = match self.state {
+            PlacementState::Normal => SW_SHOWNORMAL,
+            PlacementState::Minimized => SW_SHOWMINIMIZED,
+            PlacementState::Maximized => SW_SHOWMAXIMIZED,
+        };
 
-        return hwnd;
+        return WINDOWPLACEMENT {
+            length: std::mem::size_of::<WINDOWPLACEMENT>() as u32,
+            flags: if self.restore_to_maximized { WPF_RESTORETOMAXIMIZED } else { 0 },
+            showCmd: show_cmd as u32,
+            ptMinPosition: POINT { x: self.min_position.x, y: self.min_position.y },
+            ptMaxPosition: POINT { x: self.max_position.x, y: self.max_position.y },
+            rcNormalPosition: RECT {
+                left: self.normal_rect.left,
+                top: self.normal_rect.top,
+                right: self.normal_rect.right,
+                bottom: self.normal_rect.bottom,
+            },
+        };
     }
 }
 
+#[cfg(feature = "raw-window-handle")]
 pub use raw_window_handle::{HasRawDisplayHandle, HasRawWindowHandle};
 
+#[cfg(feature = "raw-window-handle")]
 unsafe impl HasRawWindowHandle for Window {
     fn raw_window_handle(&self) -> raw_window_handle::RawWindowHandle {
         let mut hwnd = raw_window_handle::Win32WindowHandle::empty();
@@ -394,8 +1848,59 @@ unsafe impl HasRawWindowHandle for Window {
     }
 }
 
+#[cfg(feature = "raw-window-handle")]
 unsafe impl HasRawDisplayHandle for Window {
     fn raw_display_handle(&self) -> raw_window_handle::RawDisplayHandle {
         raw_window_handle::RawDisplayHandle::Windows(raw_window_handle::WindowsDisplayHandle::empty())
     }
 }
+
+#[cfg(all(test, windows))]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn query_title_keeps_surrogate_pairs() {
+        let class = "dgews-test-query-title";
+        let builder = WindowBuilder::default().with_no_activate(true);
+        let mut manager = Manager::default().try_add_window(class, builder).unwrap();
+        let window = manager.get_mut_window(class).unwrap();
+
+        window.set_title("日本語 🎮");
+        let title = window.query_title();
+
+        // 🎮 is outside of the BMP, so it only survives if both halves of its surrogate pair do
+        assert_eq!(title, "日本語 🎮");
+        assert_eq!(title.encode_utf16().count(), 6);
+
+        window.destroy();
+    }
+
+    #[test]
+    fn maximize_sends_maximized() {
+        let class = "dgews-test-maximize";
+        let builder = WindowBuilder::default().with_no_activate(true);
+        let mut manager = Manager::default().try_add_window(class, builder).unwrap();
+        let window = manager.get_window(class).unwrap();
+        let window_id = window.get_id();
+        window.maximize();
+
+        // Gives up instead of hanging if the event never comes
+        let deadline = std::time::Instant::now() + std::time::Duration::from_secs(5);
+        let mut maximized = None;
+        manager.run(|events, control_flow, _| match events {
+            Events::WindowEvents {
+                id,
+                event: WindowEvents::Maximized { width, height },
+            } if id == window_id => {
+                maximized = Some(Size::new(width, height));
+                *control_flow = ControlFlow::Exit;
+            }
+            _ if std::time::Instant::now() >= deadline => *control_flow = ControlFlow::Exit,
+            _ => *control_flow = ControlFlow::WaitUntil(deadline),
+        });
+
+        let maximized = maximized.expect("maximize() has not sent WindowEvents::Maximized");
+        assert!(maximized.width > 0 && maximized.height > 0);
+    }
+}
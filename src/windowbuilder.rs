@@ -18,6 +18,20 @@ pub struct WindowBuilder {
     pub(crate) size: Size,
     pub(crate) resizable: bool,
     pub(crate) theme: Theme,
+    pub(crate) no_activate: bool,
+    pub(crate) blur: bool,
+    pub(crate) logical_size: bool,
+    pub(crate) want_all_keys: bool,
+    pub(crate) no_animations: bool,
+    pub(crate) no_erase_background: bool,
+    pub(crate) decorations: bool,
+    pub(crate) always_on_top: bool,
+    pub(crate) opacity: f32,
+    pub(crate) min_size: Option<Size>,
+    pub(crate) max_size: Option<Size>,
+    pub(crate) centered: bool,
+    pub(crate) maximized: bool,
+    pub(crate) minimized: bool,
 }
 
 impl Default for WindowBuilder {
@@ -29,6 +43,20 @@ impl Default for WindowBuilder {
             size: Size::new(800, 640),
             resizable: false,
             theme: Theme::default(),
+            no_activate: false,
+            blur: false,
+            logical_size: false,
+            want_all_keys: false,
+            no_animations: false,
+            no_erase_background: false,
+            decorations: true,
+            always_on_top: false,
+            opacity: 1.0f32,
+            min_size: None,
+            max_size: None,
+            centered: false,
+            maximized: false,
+            minimized: false,
         };
     }
 }
@@ -70,32 +98,34 @@ impl WindowBuilder {
         return self.icon.clone();
     }
 
-    /// Return a pointer to the title of the WindowBuilder
+    /// Returns the title of the WindowBuilder as a wide string. It must outlive the pointer taken from it
     /// 
     /// # Example
     /// 
     /// ```ignore
     /// let window_builder = WindowBuilder::new();
-    /// func(window_builder.title());
+    /// let title = window_builder.title();
+    /// func(title.as_ptr());
     /// 
     /// // Where func takes a pointer to the title of the WindowBuilder
     /// ```
-    pub fn title(&self) -> *const u16 {
-        return wchar(&self.title);
+    pub fn title(&self) -> Wstring {
+        return Wstring::from(&self.title);
     }
 
-    /// Return a pointer to the icon of the WindowBuilder
+    /// Returns the icon of the WindowBuilder as a wide string. It must outlive the pointer taken from it
     /// 
     /// # Example
     /// 
     /// ```ignore
     /// let window_builder = WindowBuilder::new();
-    /// func(window_builder.icon());
+    /// let icon = window_builder.icon();
+    /// func(icon.as_ptr());
     /// 
     /// // Where func takes a pointer to the icon of the WindowBuilder
     /// ```
-    pub fn icon(&self) -> *const u16 {
-        return wchar(&self.icon);
+    pub fn icon(&self) -> Wstring {
+        return Wstring::from(&self.icon);
     }
 
     /// Returns the position of the WindowBuilder
@@ -194,7 +224,7 @@ impl WindowBuilder {
         return self;
     }
 
-    /// Returns a WindowBuilder with a given icon
+    /// Returns a WindowBuilder with a given icon. A path with a NUL character is rejected by validate(), and an icon which cannot be found or loaded makes Manager::try_new() and Manager::try_add_window() fail with WindowError::IconNotFound or WindowError::IconLoad
     /// 
     /// # Example
     /// 
@@ -230,9 +260,36 @@ impl WindowBuilder {
     /// ```
     pub fn with_dimensions(mut self, width: i32, height: i32) -> Self {
         self.size = Size::new(width, height);
+        self.logical_size = false;
         return self;
     }
 
+    /// Returns a WindowBuilder with a given logical size (in the 96 DPI pixels). It is multiplied by the scale factor of the monitor at the position of the window when it is created, so an 800x640 window is 1200x960 pixels on a monitor with 150% scaling. This needs a DPI aware process (see ManagerBuilder::with_dpi_aware()), otherwise the system reports 100% for every monitor and stretches the 800x640 window itself, which looks blurry. with_dimensions() gives the size in the physical pixels instead
+    /// 
+    /// # Example
+    /// 
+    /// ```ignore
+    /// let window_builder = WindowBuilder::new().with_logical_size(800, 640);
+    /// assert_eq!(window_builder.is_logical_size(), true);
+    /// ```
+    pub fn with_logical_size(mut self, width: i32, height: i32) -> Self {
+        self.size = Size::new(width, height);
+        self.logical_size = true;
+        return self;
+    }
+
+    /// Returns whether the size of the WindowBuilder is in the logical pixels
+    /// 
+    /// # Example
+    /// 
+    /// ```ignore
+    /// let window_builder = WindowBuilder::new();
+    /// assert_eq!(window_builder.is_logical_size(), false);
+    /// ```
+    pub fn is_logical_size(&self) -> bool {
+        return self.logical_size;
+    }
+
     /// Returns a WindowBuilder with a give resizablity
     /// 
     /// # Example
@@ -273,4 +330,371 @@ impl WindowBuilder {
     pub fn is_resizable(&self) -> bool {
         return self.resizable;
     }
+
+    /// Returns a WindowBuilder which opens its window without stealing the focus from the active window (useful for tooltips and notification popups)
+    /// 
+    /// # Example
+    /// 
+    /// ```ignore
+    /// let window_builder = WindowBuilder::new().with_no_activate(true);
+    /// assert_eq!(window_builder.is_no_activate(), true);
+    /// ```
+    pub fn with_no_activate(mut self, no_activate: bool) -> Self {
+        self.no_activate = no_activate;
+        return self;
+    }
+
+    /// Returns whether the window opens without being activated
+    /// 
+    /// # Example
+    /// 
+    /// ```ignore
+    /// let window_builder = WindowBuilder::new();
+    /// assert_eq!(window_builder.is_no_activate(), false);
+    /// ```
+    pub fn is_no_activate(&self) -> bool {
+        return self.no_activate;
+    }
+
+    /// Returns a WindowBuilder whose window is shown, hidden, minimized and restored instantly without the animations of the system (useful for tooltips and overlays)
+    /// 
+    /// # Example
+    /// 
+    /// ```ignore
+    /// let window_builder = WindowBuilder::new().with_no_animations(true);
+    /// assert_eq!(window_builder.is_no_animations(), true);
+    /// ```
+    pub fn with_no_animations(mut self, no_animations: bool) -> Self {
+        self.no_animations = no_animations;
+        return self;
+    }
+
+    /// Returns whether the window is shown without the animations of the system
+    /// 
+    /// # Example
+    /// 
+    /// ```ignore
+    /// let window_builder = WindowBuilder::new();
+    /// assert_eq!(window_builder.is_no_animations(), false);
+    /// ```
+    pub fn is_no_animations(&self) -> bool {
+        return self.no_animations;
+    }
+
+    /// Returns a WindowBuilder whose window blurs whatever is behind it (a frosted glass look). Only the black parts of the client area are see-through, so clear it with black where the blur should show
+    /// 
+    /// # Example
+    /// 
+    /// ```ignore
+    /// let window_builder = WindowBuilder::new().with_blur(true);
+    /// assert_eq!(window_builder.is_blur(), true);
+    /// ```
+    pub fn with_blur(mut self, blur: bool) -> Self {
+        self.blur = blur;
+        return self;
+    }
+
+    /// Returns whether the window blurs what is behind it
+    /// 
+    /// # Example
+    /// 
+    /// ```ignore
+    /// let window_builder = WindowBuilder::new();
+    /// assert_eq!(window_builder.is_blur(), false);
+    /// ```
+    pub fn is_blur(&self) -> bool {
+        return self.blur;
+    }
+
+    /// Returns a WindowBuilder whose window gets all the keys (including the arrows, Tab and Enter) even when it is embedded into a dialog-like host window which would use them for its navigation
+    /// 
+    /// # Example
+    /// 
+    /// ```ignore
+    /// let window_builder = WindowBuilder::new().with_want_all_keys(true);
+    /// assert_eq!(window_builder.is_want_all_keys(), true);
+    /// ```
+    pub fn with_want_all_keys(mut self, want_all_keys: bool) -> Self {
+        self.want_all_keys = want_all_keys;
+        return self;
+    }
+
+    /// Returns whether the window gets all the keys
+    /// 
+    /// # Example
+    /// 
+    /// ```ignore
+    /// let window_builder = WindowBuilder::new();
+    /// assert_eq!(window_builder.is_want_all_keys(), false);
+    /// ```
+    pub fn is_want_all_keys(&self) -> bool {
+        return self.want_all_keys;
+    }
+
+    /// Returns a WindowBuilder whose window never lets the system erase its background before WM_PAINT. It removes the flicker while resizing a window whose content is drawn entirely by the program (e.g. with a gpu surface)
+    /// 
+    /// # Example
+    /// 
+    /// ```ignore
+    /// let window_builder = WindowBuilder::new().with_no_erase_background(true);
+    /// assert_eq!(window_builder.is_no_erase_background(), true);
+    /// ```
+    pub fn with_no_erase_background(mut self, no_erase_background: bool) -> Self {
+        self.no_erase_background = no_erase_background;
+        return self;
+    }
+
+    /// Returns whether the system is kept from erasing the background of the window
+    /// 
+    /// # Example
+    /// 
+    /// ```ignore
+    /// let window_builder = WindowBuilder::new();
+    /// assert_eq!(window_builder.is_no_erase_background(), false);
+    /// ```
+    pub fn is_no_erase_background(&self) -> bool {
+        return self.no_erase_background;
+    }
+
+    /// Returns a WindowBuilder whose window has the title bar and the border (the default) or is borderless for a custom drawn chrome. A borderless window can still be resized from its edges if with_resizable(true) is set as well
+    /// 
+    /// # Example
+    /// 
+    /// ```ignore
+    /// let window_builder = WindowBuilder::new().with_decorations(false).with_resizable(true);
+    /// assert_eq!(window_builder.is_decorated(), false);
+    /// ```
+    pub fn with_decorations(mut self, decorations: bool) -> Self {
+        self.decorations = decorations;
+        return self;
+    }
+
+    /// Returns whether the window has the title bar and the border
+    /// 
+    /// # Example
+    /// 
+    /// ```ignore
+    /// let window_builder = WindowBuilder::new();
+    /// assert_eq!(window_builder.is_decorated(), true);
+    /// ```
+    pub fn is_decorated(&self) -> bool {
+        return self.decorations;
+    }
+
+    /// Returns a WindowBuilder whose window starts above all the other windows (see Window::set_always_on_top())
+    /// 
+    /// # Example
+    /// 
+    /// ```ignore
+    /// let window_builder = WindowBuilder::new().with_always_on_top(true);
+    /// assert_eq!(window_builder.is_always_on_top(), true);
+    /// ```
+    pub fn with_always_on_top(mut self, always_on_top: bool) -> Self {
+        self.always_on_top = always_on_top;
+        return self;
+    }
+
+    /// Returns whether the window starts above all the other windows
+    /// 
+    /// # Example
+    /// 
+    /// ```ignore
+    /// let window_builder = WindowBuilder::new();
+    /// assert_eq!(window_builder.is_always_on_top(), false);
+    /// ```
+    pub fn is_always_on_top(&self) -> bool {
+        return self.always_on_top;
+    }
+
+    /// Returns a WindowBuilder whose window starts with the given opacity from 0.0 (invisible) to 1.0 (opaque, the default). See Window::set_opacity()
+    /// 
+    /// # Example
+    /// 
+    /// ```ignore
+    /// let window_builder = WindowBuilder::new().with_opacity(0.8);
+    /// assert_eq!(window_builder.get_opacity(), 0.8);
+    /// ```
+    pub fn with_opacity(mut self, opacity: f32) -> Self {
+        self.opacity = opacity.clamp(0.0f32, 1.0f32);
+        return self;
+    }
+
+    /// Returns the opacity which the window starts with
+    /// 
+    /// # Example
+    /// 
+    /// ```ignore
+    /// let window_builder = WindowBuilder::new();
+    /// assert_eq!(window_builder.get_opacity(), 1.0);
+    /// ```
+    pub fn get_opacity(&self) -> f32 {
+        return self.opacity;
+    }
+
+    /// Returns a WindowBuilder whose window cannot be resized below the given client area size. It overrides Manager::set_min_size_all() for this window
+    /// 
+    /// # Example
+    /// 
+    /// ```ignore
+    /// let window_builder = WindowBuilder::new().with_resizable(true).with_min_size(320, 240);
+    /// assert_eq!(window_builder.get_min_size(), Some(Size::new(320, 240)));
+    /// ```
+    pub fn with_min_size(mut self, width: i32, height: i32) -> Self {
+        self.min_size = Some(Size::new(width, height));
+        return self;
+    }
+
+    /// Returns the minimum client area size of the window
+    /// 
+    /// # Example
+    /// 
+    /// ```ignore
+    /// let window_builder = WindowBuilder::new();
+    /// assert_eq!(window_builder.get_min_size(), None);
+    /// ```
+    pub fn get_min_size(&self) -> Option<Size> {
+        return self.min_size;
+    }
+
+    /// Returns a WindowBuilder whose window cannot be resized above the given client area size. Without it the window can be maximized to the whole monitor
+    /// 
+    /// # Example
+    /// 
+    /// ```ignore
+    /// let window_builder = WindowBuilder::new().with_resizable(true).with_max_size(1920, 1080);
+    /// assert_eq!(window_builder.get_max_size(), Some(Size::new(1920, 1080)));
+    /// ```
+    pub fn with_max_size(mut self, width: i32, height: i32) -> Self {
+        self.max_size = Some(Size::new(width, height));
+        return self;
+    }
+
+    /// Returns the maximum client area size of the window
+    /// 
+    /// # Example
+    /// 
+    /// ```ignore
+    /// let window_builder = WindowBuilder::new();
+    /// assert_eq!(window_builder.get_max_size(), None);
+    /// ```
+    pub fn get_max_size(&self) -> Option<Size> {
+        return self.max_size;
+    }
+
+    /// Returns a WindowBuilder whose window is placed at the center of the work area of the primary monitor instead of at its position
+    /// 
+    /// # Example
+    /// 
+    /// ```ignore
+    /// let window_builder = WindowBuilder::new().with_centered(true);
+    /// assert_eq!(window_builder.is_centered(), true);
+    /// ```
+    pub fn with_centered(mut self, centered: bool) -> Self {
+        self.centered = centered;
+        return self;
+    }
+
+    /// Returns whether the window is placed at the center of the primary monitor
+    /// 
+    /// # Example
+    /// 
+    /// ```ignore
+    /// let window_builder = WindowBuilder::new();
+    /// assert_eq!(window_builder.is_centered(), false);
+    /// ```
+    pub fn is_centered(&self) -> bool {
+        return self.centered;
+    }
+
+    /// Returns a WindowBuilder whose window is shown maximized at first. It wins over with_minimized()
+    /// 
+    /// # Example
+    /// 
+    /// ```ignore
+    /// let window_builder = WindowBuilder::new().with_maximized(true);
+    /// assert_eq!(window_builder.is_maximized(), true);
+    /// ```
+    pub fn with_maximized(mut self, maximized: bool) -> Self {
+        self.maximized = maximized;
+        return self;
+    }
+
+    /// Returns whether the window is shown maximized at first
+    /// 
+    /// # Example
+    /// 
+    /// ```ignore
+    /// let window_builder = WindowBuilder::new();
+    /// assert_eq!(window_builder.is_maximized(), false);
+    /// ```
+    pub fn is_maximized(&self) -> bool {
+        return self.maximized;
+    }
+
+    /// Returns a WindowBuilder whose window is shown minimized at first
+    /// 
+    /// # Example
+    /// 
+    /// ```ignore
+    /// let window_builder = WindowBuilder::new().with_minimized(true);
+    /// assert_eq!(window_builder.is_minimized(), true);
+    /// ```
+    pub fn with_minimized(mut self, minimized: bool) -> Self {
+        self.minimized = minimized;
+        return self;
+    }
+
+    /// Returns whether the window is shown minimized at first
+    /// 
+    /// # Example
+    /// 
+    /// ```ignore
+    /// let window_builder = WindowBuilder::new();
+    /// assert_eq!(window_builder.is_minimized(), false);
+    /// ```
+    pub fn is_minimized(&self) -> bool {
+        return self.minimized;
+    }
+
+    /// Checks whether the size and the position can make a visible window and whether the icon path can be given to Win32. A window with a zero or negative size would be created but never seen, and an icon path with a NUL character would be cut short. The Manager clamps the size to 1..=i16::MAX itself, but it refuses to create a window with an invalid position or icon path and returns this error from try_new() and try_add_window()
+    /// 
+    /// # Example
+    /// 
+    /// ```ignore
+    /// let window_builder = WindowBuilder::new().with_dimensions(0, 0);
+    /// assert_eq!(window_builder.validate(), Err(WindowError::InvalidSize { width: 0, height: 0 }));
+    ///
+    /// let window_builder = WindowBuilder::new().with_icon("icon.ico\0.exe");
+    /// assert_eq!(window_builder.validate(), Err(WindowError::IconPathNul(String::from("icon.ico\0.exe"))));
+    /// ```
+    pub fn validate(&self) -> Result<(), WindowError> {
+        let sizes = 1..=i16::MAX as i32;
+        let positions = i16::MIN as i32..=i16::MAX as i32;
+
+        if !sizes.contains(&self.size.width) || !sizes.contains(&self.size.height) {
+            return Err(WindowError::InvalidSize {
+                width: self.size.width,
+                height: self.size.height,
+            });
+        }
+
+        if !positions.contains(&self.pos.x) || !positions.contains(&self.pos.y) {
+            return Err(WindowError::InvalidPosition {
+                x: self.pos.x,
+                y: self.pos.y,
+            });
+        }
+
+        if self.icon.contains('\0') {
+            return Err(WindowError::IconPathNul(self.icon.clone()));
+        }
+
+        return Ok(());
+    }
+
+    pub(crate) fn clamped(mut self) -> Self {
+        let max = i16::MAX as i32;
+        self.size = self.size.clamp(Size::new(1, 1), Size::new(max, max));
+        return self;
+    }
 }
@@ -0,0 +1,82 @@
+use std::sync::{Arc, Mutex};
+
+use winapi::{shared::minwindef::UINT, um::winuser::WM_USER};
+
+use crate::prelude::*;
+
+pub(crate) const WM_DGEWS_SET_TIMER: UINT = WM_USER + 1;
+pub(crate) const WM_DGEWS_KILL_TIMER: UINT = WM_USER + 2;
+pub(crate) const WM_DGEWS_DESTROY: UINT = WM_USER + 3;
+pub(crate) const WM_DGEWS_TEXT_INPUT_AREA: UINT = WM_USER + 4;
+pub(crate) const WM_DGEWS_DRAG: UINT = WM_USER + 5;
+pub(crate) const WM_DGEWS_SYSTEM_MENU: UINT = WM_USER + 6;
+
+pub(crate) type SizeConstraintsCallback = Box<dyn FnMut(&mut MinMaxInfo) + Send>;
+
+#[derive(Default)]
+pub(crate) struct WindowState {
+    pub(crate) size_constraints: Option<SizeConstraintsCallback>,
+    pub(crate) constraints: SizeConstraints,
+    pub(crate) confine_to_workarea: bool,
+    pub(crate) accelerators: usize,
+    pub(crate) titlebar_height: Option<i32>,
+    pub(crate) always_on_bottom: bool,
+    pub(crate) text_input_area: Option<Rect>,
+    pub(crate) cursor: usize,
+    pub(crate) raw_messages: bool,
+    pub(crate) drop_effect: DropEffect,
+    pub(crate) want_all_keys: bool,
+    pub(crate) held_buttons: usize,
+    pub(crate) tracking_leave: bool,
+    pub(crate) snap_layouts: bool,
+    pub(crate) monitor: MonitorId,
+    pub(crate) prevent_close: bool,
+    pub(crate) disabled: bool,
+    pub(crate) no_double_click_maximize: bool,
+    pub(crate) input_region: Option<Rect>,
+    pub(crate) no_erase_background: bool,
+    pub(crate) size_move: bool,
+    pub(crate) resizing: bool,
+}
+
+impl std::fmt::Debug for WindowState {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("WindowState")
+            .field("size_constraints", &self.size_constraints.is_some())
+            .field("constraints", &self.constraints)
+            .field("confine_to_workarea", &self.confine_to_workarea)
+            .field("accelerators", &self.accelerators)
+            .field("titlebar_height", &self.titlebar_height)
+            .field("always_on_bottom", &self.always_on_bottom)
+            .field("text_input_area", &self.text_input_area)
+            .field("cursor", &self.cursor)
+            .field("raw_messages", &self.raw_messages)
+            .field("drop_effect", &self.drop_effect)
+            .field("want_all_keys", &self.want_all_keys)
+            .field("held_buttons", &self.held_buttons)
+            .field("tracking_leave", &self.tracking_leave)
+            .field("snap_layouts", &self.snap_layouts)
+            .field("monitor", &self.monitor)
+            .field("prevent_close", &self.prevent_close)
+            .field("disabled", &self.disabled)
+            .field("no_double_click_maximize", &self.no_double_click_maximize)
+            .field("input_region", &self.input_region)
+            .field("no_erase_background", &self.no_erase_background)
+            .field("size_move", &self.size_move)
+            .field("resizing", &self.resizing)
+            .finish()
+    }
+}
+
+#[derive(Debug)]
+pub(crate) struct WindowData {
+    pub(crate) id: WindowId,
+    pub(crate) msger: Messenger,
+    pub(crate) state: Arc<Mutex<WindowState>>,
+}
+
+impl WindowData {
+    pub(crate) fn new(id: WindowId, msger: Messenger, state: Arc<Mutex<WindowState>>) -> Self {
+        return Self { id, msger, state };
+    }
+}